@@ -0,0 +1,102 @@
+use crate::point_range_iterator::PointRangeIterator;
+use crate::points::Points;
+
+/// Returns the ends of the cells along each axis from `min` to `max`, either those any part of
+/// the box overlaps or only those it covers entirely, or `None` if there are none.
+fn cell_ends<const N: usize>(
+    min: [f64; N],
+    max: [f64; N],
+    covered: bool,
+) -> Option<([i32; N], [i32; N])> {
+    let (mut start, mut end) = ([0; N], [0; N]);
+    for axis in 0..N {
+        // Also rejects NaN.
+        if min[axis].partial_cmp(&max[axis]) != Some(std::cmp::Ordering::Less) {
+            return None;
+        }
+        let (low, high) = if covered {
+            (min[axis].ceil(), max[axis].floor())
+        } else {
+            (min[axis].floor(), max[axis].ceil())
+        };
+        if low >= high {
+            return None;
+        }
+        start[axis] = low as i32;
+        end[axis] = high as i32;
+    }
+    Some((start, end))
+}
+
+/// The integer cells a box of floating-point coordinates overlaps, where the cell at `p` spans
+/// from `p` to `p + 1` along each axis. Coordinates out of the range of `i32` saturate.
+///
+/// # Examples
+/// ```
+/// # use euclid::{Box2D, Point2D};
+/// # use euclid_ext::CellCoverage;
+/// enum Space {}
+/// let b = Box2D::new(Point2D::<f32, Space>::new(0.5, -1.0), Point2D::new(3.0, 1.5));
+/// assert_eq!(Box2D::new(Point2D::new(0, -1), Point2D::new(3, 2)), b.touched_cells());
+/// assert_eq!(Box2D::new(Point2D::new(1, -1), Point2D::new(3, 1)), b.covered_cells());
+/// assert_eq!(
+///     vec![Point2D::new(1, -1), Point2D::new(2, -1), Point2D::new(1, 0), Point2D::new(2, 0)],
+///     b.covered_points().collect::<Vec<_>>());
+/// ```
+pub trait CellCoverage {
+    type Cells;
+
+    type Iter: Iterator;
+
+    /// Returns the cells which overlap the box by more than an edge.
+    fn touched_cells(self) -> Self::Cells;
+
+    /// Returns the cells which are entirely inside the box.
+    fn covered_cells(self) -> Self::Cells;
+
+    /// Iterates the cells which overlap the box by more than an edge, in the order of `Points`.
+    fn touched_points(self) -> Self::Iter;
+
+    /// Iterates the cells which are entirely inside the box, in the order of `Points`.
+    fn covered_points(self) -> Self::Iter;
+}
+
+macro_rules! impl_cell_coverage {
+    ($box:ident, $point:ident, $($t:ty),*) => {
+        $(
+            impl<U> CellCoverage for euclid::$box<$t, U> {
+                type Cells = euclid::$box<i32, U>;
+
+                type Iter = PointRangeIterator<euclid::$point<i32, U>>;
+
+                fn touched_cells(self) -> Self::Cells {
+                    let (min, max) = (self.min.to_array(), self.max.to_array());
+                    match cell_ends(min.map(f64::from), max.map(f64::from), false) {
+                        Some((start, end)) => euclid::$box::new(start.into(), end.into()),
+                        None => euclid::$box::zero(),
+                    }
+                }
+
+                fn covered_cells(self) -> Self::Cells {
+                    let (min, max) = (self.min.to_array(), self.max.to_array());
+                    match cell_ends(min.map(f64::from), max.map(f64::from), true) {
+                        Some((start, end)) => euclid::$box::new(start.into(), end.into()),
+                        None => euclid::$box::zero(),
+                    }
+                }
+
+                fn touched_points(self) -> Self::Iter {
+                    self.touched_cells().points()
+                }
+
+                fn covered_points(self) -> Self::Iter {
+                    self.covered_cells().points()
+                }
+            }
+        )*
+    };
+}
+
+impl_cell_coverage!(Box2D, Point2D, f32, f64);
+
+impl_cell_coverage!(Box3D, Point3D, f32, f64);
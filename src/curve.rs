@@ -0,0 +1,238 @@
+use euclid::{Point2D, Point3D};
+use std::convert::TryFrom;
+
+/// Space-filling curves which visit every cell of a power-of-two grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Curve {
+    /// Z-order curve, interleaving the bits of the coordinates with x as the least significant.
+    Morton,
+    Hilbert,
+}
+
+impl Curve {
+    pub(crate) fn encode(self, coords: &mut [u32], bits: u32) -> u128 {
+        match self {
+            Self::Morton => coords.reverse(),
+            Self::Hilbert => axes_to_transpose(coords, bits),
+        }
+        interleave(coords, bits)
+    }
+
+    pub(crate) fn decode(self, index: u128, bits: u32, coords: &mut [u32]) {
+        deinterleave(index, bits, coords);
+        match self {
+            Self::Morton => coords.reverse(),
+            Self::Hilbert => transpose_to_axes(coords, bits),
+        }
+    }
+}
+
+fn interleave(coords: &[u32], bits: u32) -> u128 {
+    let mut index = 0;
+    for bit in (0..bits).rev() {
+        for coord in coords {
+            index = index << 1 | u128::from(coord >> bit & 1);
+        }
+    }
+    index
+}
+
+fn deinterleave(index: u128, bits: u32, coords: &mut [u32]) {
+    let dimensions = coords.len() as u32;
+    for (axis, coord) in coords.iter_mut().enumerate() {
+        *coord = 0;
+        for bit in 0..bits {
+            let shift = bit * dimensions + dimensions - 1 - axis as u32;
+            *coord |= ((index >> shift) as u32 & 1) << bit;
+        }
+    }
+}
+
+// The Hilbert transforms follow J. Skilling, "Programming the Hilbert curve" (2004).
+
+fn axes_to_transpose(x: &mut [u32], bits: u32) {
+    if bits == 0 {
+        return;
+    }
+    let last = x.len() - 1;
+    let mut q = 1 << (bits - 1);
+    while q > 1 {
+        let p = q - 1;
+        for i in 0..=last {
+            if x[i] & q != 0 {
+                x[0] ^= p;
+            } else {
+                let t = (x[0] ^ x[i]) & p;
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+        q >>= 1;
+    }
+    for i in 1..=last {
+        x[i] ^= x[i - 1];
+    }
+    let mut t = 0;
+    let mut q = 1 << (bits - 1);
+    while q > 1 {
+        if x[last] & q != 0 {
+            t ^= q - 1;
+        }
+        q >>= 1;
+    }
+    for coord in x {
+        *coord ^= t;
+    }
+}
+
+fn transpose_to_axes(x: &mut [u32], bits: u32) {
+    if bits == 0 {
+        return;
+    }
+    let last = x.len() - 1;
+    let t = x[last] >> 1;
+    for i in (1..=last).rev() {
+        x[i] ^= x[i - 1];
+    }
+    x[0] ^= t;
+    for bit in 1..bits {
+        let q = 1 << bit;
+        let p = q - 1;
+        for i in (0..=last).rev() {
+            if x[i] & q != 0 {
+                x[0] ^= p;
+            } else {
+                let t = (x[0] ^ x[i]) & p;
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+    }
+}
+
+/// # Examples
+/// ```
+/// # use euclid::Point2D;
+/// # use euclid_ext::curve::morton_encode_2d;
+/// enum Space {}
+/// assert_eq!(0b1001, morton_encode_2d(Point2D::<u32, Space>::new(1, 2)));
+/// ```
+pub fn morton_encode_2d<U>(point: Point2D<u32, U>) -> u64 {
+    Curve::Morton.encode(&mut point.to_array(), 32) as u64
+}
+
+/// # Examples
+/// ```
+/// # use euclid::Point2D;
+/// # use euclid_ext::curve::morton_decode_2d;
+/// enum Space {}
+/// assert_eq!(Point2D::<u32, Space>::new(1, 2), morton_decode_2d(0b1001));
+/// ```
+pub fn morton_decode_2d<U>(index: u64) -> Point2D<u32, U> {
+    let mut coords = [0; 2];
+    Curve::Morton.decode(u128::from(index), 32, &mut coords);
+    coords.into()
+}
+
+/// # Examples
+/// ```
+/// # use euclid::Point3D;
+/// # use euclid_ext::curve::morton_encode_3d;
+/// enum Space {}
+/// assert_eq!(0b100010, morton_encode_3d(Point3D::<u32, Space>::new(0, 1, 2)));
+/// ```
+pub fn morton_encode_3d<U>(point: Point3D<u32, U>) -> u128 {
+    Curve::Morton.encode(&mut point.to_array(), 32)
+}
+
+/// # Examples
+/// ```
+/// # use euclid::Point3D;
+/// # use euclid_ext::curve::morton_decode_3d;
+/// enum Space {}
+/// assert_eq!(Point3D::<u32, Space>::new(0, 1, 2), morton_decode_3d(0b100010));
+/// ```
+pub fn morton_decode_3d<U>(index: u128) -> Point3D<u32, U> {
+    let mut coords = [0; 3];
+    Curve::Morton.decode(index, 32, &mut coords);
+    coords.into()
+}
+
+/// Returns the index of `point` on the Hilbert curve filling a grid of `2^bits` cells per side.
+/// Only the lowest `bits` bits of the coordinates are used.
+///
+/// # Panics
+/// Panics if `bits` is greater than 32.
+///
+/// # Examples
+/// ```
+/// # use euclid::Point2D;
+/// # use euclid_ext::curve::{hilbert_decode_2d, hilbert_encode_2d};
+/// enum Space {}
+/// let point = Point2D::<u32, Space>::new(5, 6);
+/// assert_eq!(point, hilbert_decode_2d(hilbert_encode_2d(point, 3), 3));
+/// ```
+pub fn hilbert_encode_2d<U>(point: Point2D<u32, U>, bits: u32) -> u64 {
+    assert!(bits <= 32, "bits must be at most 32");
+    // The index has `2 * bits` bits, which fit in `u64`.
+    u64::try_from(Curve::Hilbert.encode(&mut point.to_array(), bits)).unwrap()
+}
+
+/// Returns the point at `index` on the Hilbert curve filling a grid of `2^bits` cells per side.
+/// Only the lowest `2 * bits` bits of the index are used.
+///
+/// # Panics
+/// Panics if `bits` is greater than 32.
+///
+/// # Examples
+/// ```
+/// # use euclid::Point2D;
+/// # use euclid_ext::curve::hilbert_decode_2d;
+/// enum Space {}
+/// assert_eq!(Point2D::<u32, Space>::new(1, 0), hilbert_decode_2d(3, 1));
+/// ```
+pub fn hilbert_decode_2d<U>(index: u64, bits: u32) -> Point2D<u32, U> {
+    assert!(bits <= 32, "bits must be at most 32");
+    let mut coords = [0; 2];
+    Curve::Hilbert.decode(u128::from(index), bits, &mut coords);
+    coords.into()
+}
+
+/// Returns the index of `point` on the Hilbert curve filling a grid of `2^bits` cells per side.
+/// Only the lowest `bits` bits of the coordinates are used.
+///
+/// # Panics
+/// Panics if `bits` is greater than 32.
+///
+/// # Examples
+/// ```
+/// # use euclid::Point3D;
+/// # use euclid_ext::curve::{hilbert_decode_3d, hilbert_encode_3d};
+/// enum Space {}
+/// let point = Point3D::<u32, Space>::new(5, 6, 2);
+/// assert_eq!(point, hilbert_decode_3d(hilbert_encode_3d(point, 3), 3));
+/// ```
+pub fn hilbert_encode_3d<U>(point: Point3D<u32, U>, bits: u32) -> u128 {
+    assert!(bits <= 32, "bits must be at most 32");
+    Curve::Hilbert.encode(&mut point.to_array(), bits)
+}
+
+/// Returns the point at `index` on the Hilbert curve filling a grid of `2^bits` cells per side.
+/// Only the lowest `3 * bits` bits of the index are used.
+///
+/// # Panics
+/// Panics if `bits` is greater than 32.
+///
+/// # Examples
+/// ```
+/// # use euclid::Point3D;
+/// # use euclid_ext::curve::hilbert_decode_3d;
+/// enum Space {}
+/// assert_eq!(Point3D::<u32, Space>::new(0, 0, 1), hilbert_decode_3d(1, 1));
+/// ```
+pub fn hilbert_decode_3d<U>(index: u128, bits: u32) -> Point3D<u32, U> {
+    assert!(bits <= 32, "bits must be at most 32");
+    let mut coords = [0; 3];
+    Curve::Hilbert.decode(index, bits, &mut coords);
+    coords.into()
+}
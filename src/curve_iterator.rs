@@ -0,0 +1,153 @@
+use crate::curve::Curve;
+use crate::point_range_iterator::{
+    PointRange, PointRangeIteratorItem, PointRangeIteratorPrimitive,
+};
+use crate::to_point_range::IntoPointRange;
+use std::convert::TryFrom;
+use std::{fmt, hash, iter};
+
+fn extent(count: usize) -> u32 {
+    u32::try_from(count).expect("extent exceeds u32::MAX")
+}
+
+pub trait CurveIteratorItem: PointRangeIteratorItem {
+    type Offsets: Copy + AsRef<[u32]> + AsMut<[u32]> + fmt::Debug + Eq + hash::Hash;
+
+    /// Returns the number of points along each axis of `range`.
+    fn extents(range: &PointRange<Self>) -> Self::Offsets;
+
+    /// Returns the point `offsets` steps away from `self` along each axis.
+    fn offset(self, offsets: Self::Offsets) -> Self;
+}
+
+impl<T: PointRangeIteratorPrimitive, U> CurveIteratorItem for euclid::Point2D<T, U> {
+    type Offsets = [u32; 2];
+
+    fn extents(range: &PointRange<Self>) -> Self::Offsets {
+        let counts = range.counts();
+        [extent(counts[0]), extent(counts[1])]
+    }
+
+    fn offset(self, offsets: Self::Offsets) -> Self {
+        Self::new(
+            self.x.offset(offsets[0] as usize),
+            self.y.offset(offsets[1] as usize),
+        )
+    }
+}
+
+impl<T: PointRangeIteratorPrimitive, U> CurveIteratorItem for euclid::Point3D<T, U> {
+    type Offsets = [u32; 3];
+
+    fn extents(range: &PointRange<Self>) -> Self::Offsets {
+        let counts = range.counts();
+        [extent(counts[0]), extent(counts[1]), extent(counts[2])]
+    }
+
+    fn offset(self, offsets: Self::Offsets) -> Self {
+        Self::new(
+            self.x.offset(offsets[0] as usize),
+            self.y.offset(offsets[1] as usize),
+            self.z.offset(offsets[2] as usize),
+        )
+    }
+}
+
+/// Iterates the points of a range along a space-filling curve laid over it from `range.start`,
+/// skipping the parts of the curve outside the range.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CurveIterator<T: CurveIteratorItem> {
+    start: T,
+    extents: T::Offsets,
+    curve: Curve,
+    bits: u32,
+    index: u128,
+    remaining: usize,
+}
+
+impl<T: CurveIteratorItem> CurveIterator<T> {
+    /// # Panics
+    /// Panics if the range has more than `u32::MAX` points along an axis,
+    /// or more than `usize::MAX` points in total.
+    pub fn new<R: IntoPointRange<Point = T>>(range: R, curve: Curve) -> Self {
+        let range = range.into_point_range();
+        let extents = T::extents(&range);
+        let max = extents.as_ref().iter().copied().max().unwrap_or(0);
+        Self {
+            start: range.start,
+            extents,
+            curve,
+            bits: 32 - max.saturating_sub(1).leading_zeros(),
+            index: 0,
+            remaining: extents
+                .as_ref()
+                .iter()
+                .try_fold(1, |len: usize, &n| len.checked_mul(n as usize))
+                .expect("range has more than usize::MAX points"),
+        }
+    }
+}
+
+/// # Examples
+/// ```
+/// # use euclid::Point2D;
+/// # use euclid_ext::curve::Curve;
+/// # use euclid_ext::curve_iterator::CurveIterator;
+/// enum Space {}
+/// let mut i = CurveIterator::new(Point2D::<i64, Space>::new(10, 20)..Point2D::<i64, Space>::new(13, 22), Curve::Morton);
+/// assert_eq!(Some(Point2D::new(10, 20)), i.next());
+/// assert_eq!(Some(Point2D::new(11, 20)), i.next());
+/// assert_eq!(Some(Point2D::new(10, 21)), i.next());
+/// assert_eq!(Some(Point2D::new(11, 21)), i.next());
+/// assert_eq!(Some(Point2D::new(12, 20)), i.next());
+/// assert_eq!(Some(Point2D::new(12, 21)), i.next());
+/// assert_eq!(None, i.next());
+/// ```
+impl<T: CurveIteratorItem> Iterator for CurveIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let dimensions = self.extents.as_ref().len() as u32;
+        loop {
+            // Each run of indices aligned to `2^(dimensions * level)` covers an aligned cube with
+            // sides of `2^level`, so whole cubes outside the range are skipped at once.
+            let mut level = if self.index == 0 {
+                self.bits
+            } else {
+                (self.index.trailing_zeros() / dimensions).min(self.bits)
+            };
+            loop {
+                let mut offsets = self.extents;
+                self.curve.decode(self.index, self.bits, offsets.as_mut());
+                let mask = u32::MAX.checked_shl(level).unwrap_or(0);
+                let outside = offsets.as_mut().iter_mut().zip(self.extents.as_ref()).any(
+                    |(offset, &extent)| {
+                        *offset &= mask;
+                        *offset >= extent
+                    },
+                );
+                if outside {
+                    self.index += 1 << (dimensions * level);
+                    break;
+                }
+                if level == 0 {
+                    self.index += 1;
+                    self.remaining -= 1;
+                    return Some(self.start.offset(offsets));
+                }
+                level -= 1;
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: CurveIteratorItem> ExactSizeIterator for CurveIterator<T> {}
+
+impl<T: CurveIteratorItem> iter::FusedIterator for CurveIterator<T> {}
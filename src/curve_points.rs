@@ -0,0 +1,44 @@
+use crate::curve::Curve;
+use crate::curve_iterator::{CurveIterator, CurveIteratorItem};
+use crate::to_point_range::IntoPointRange;
+
+/// # Examples
+/// ```
+/// # use euclid::{Box2D, Point2D};
+/// # use euclid_ext::CurvePoints;
+/// enum Space {}
+/// let range = Box2D::new(Point2D::<i64, Space>::new(0, 0), Point2D::<i64, Space>::new(2, 2));
+/// assert_eq!(
+///     vec![Point2D::new(0, 0), Point2D::new(0, 1), Point2D::new(1, 1), Point2D::new(1, 0)],
+///     range.hilbert_points().collect::<Vec<_>>());
+/// ```
+pub trait CurvePoints {
+    type Iter: Iterator;
+
+    fn curve_points(self, curve: Curve) -> Self::Iter;
+
+    fn morton_points(self) -> Self::Iter
+    where
+        Self: Sized,
+    {
+        self.curve_points(Curve::Morton)
+    }
+
+    fn hilbert_points(self) -> Self::Iter
+    where
+        Self: Sized,
+    {
+        self.curve_points(Curve::Hilbert)
+    }
+}
+
+impl<T: IntoPointRange> CurvePoints for T
+where
+    T::Point: CurveIteratorItem,
+{
+    type Iter = CurveIterator<T::Point>;
+
+    fn curve_points(self, curve: Curve) -> Self::Iter {
+        CurveIterator::new(self, curve)
+    }
+}
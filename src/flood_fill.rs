@@ -0,0 +1,51 @@
+use crate::flood_fill_iterator::{FloodFillIterator, FloodFillIteratorItem};
+use crate::to_point_range::IntoPointRange;
+
+/// # Examples
+/// ```
+/// # use euclid::{Box2D, Point2D};
+/// # use euclid_ext::{FloodFill, Neighborhood2D};
+/// enum Space {}
+/// let bounds = Box2D::new(Point2D::<i32, Space>::new(0, 0), Point2D::new(8, 8));
+/// let open = |p: Point2D<i32, Space>| p.x != 4 || p.y == 6;
+/// let region = Point2D::new(1, 1).flood_fill_within(bounds, Neighborhood2D::N4, open);
+/// assert_eq!(57, region.count());
+/// let region = Point2D::new(1, 1).flood_fill_within(bounds, Neighborhood2D::N4, |p| p.x != 4);
+/// assert_eq!(32, region.count());
+/// ```
+pub trait FloodFill: FloodFillIteratorItem {
+    /// Iterates the points connected to `self` through points satisfying `predicate`.
+    fn flood_fill<F: FnMut(Self) -> bool>(
+        self,
+        neighborhood: Self::Neighborhood,
+        predicate: F,
+    ) -> FloodFillIterator<Self, F>;
+
+    /// Iterates the points in `bounds` connected to `self` through points in `bounds` satisfying
+    /// `predicate`.
+    fn flood_fill_within<B: IntoPointRange<Point = Self>, F: FnMut(Self) -> bool>(
+        self,
+        bounds: B,
+        neighborhood: Self::Neighborhood,
+        predicate: F,
+    ) -> FloodFillIterator<Self, F>;
+}
+
+impl<T: FloodFillIteratorItem> FloodFill for T {
+    fn flood_fill<F: FnMut(Self) -> bool>(
+        self,
+        neighborhood: Self::Neighborhood,
+        predicate: F,
+    ) -> FloodFillIterator<Self, F> {
+        FloodFillIterator::new(self, neighborhood, predicate)
+    }
+
+    fn flood_fill_within<B: IntoPointRange<Point = Self>, F: FnMut(Self) -> bool>(
+        self,
+        bounds: B,
+        neighborhood: Self::Neighborhood,
+        predicate: F,
+    ) -> FloodFillIterator<Self, F> {
+        FloodFillIterator::with_bounds(self, bounds, neighborhood, predicate)
+    }
+}
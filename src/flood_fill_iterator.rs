@@ -0,0 +1,281 @@
+use crate::neighborhood::{Neighborhood2D, Neighborhood3D};
+use crate::point_range_iterator::{
+    offset_bounds, PointRange, PointRangeIteratorItem, PointRangeIteratorPrimitive,
+};
+use crate::to_point_range::IntoPointRange;
+use std::collections::{BTreeMap, HashMap};
+use std::{fmt, iter, ops};
+
+// Far enough from any seed that no point beyond it is representable.
+const UNBOUNDED: i128 = i128::MAX / 4;
+
+pub trait FloodFillIteratorItem: PointRangeIteratorItem {
+    type Neighborhood: Copy;
+
+    /// Returns the offsets of the rows next to a row within `neighborhood`, padded to the y and z
+    /// axes, with whether the cells one step along the x axis in them are in it too.
+    fn row_offsets(neighborhood: Self::Neighborhood) -> Vec<([i128; 2], bool)>;
+
+    /// Returns the first and last offsets from `self` along each axis of the points in `bounds`,
+    /// padded to three axes.
+    fn offset_bounds(self, bounds: Option<&PointRange<Self>>) -> ([i128; 3], [i128; 3]);
+
+    /// Returns the point at `offset` from `self`, or `None` if it is not representable.
+    fn at(self, offset: [i128; 3]) -> Option<Self>;
+}
+
+impl<T: PointRangeIteratorPrimitive, U> FloodFillIteratorItem for euclid::Point2D<T, U> {
+    type Neighborhood = Neighborhood2D;
+
+    fn row_offsets(neighborhood: Self::Neighborhood) -> Vec<([i128; 2], bool)> {
+        [-1, 1]
+            .iter()
+            .map(|&y| ([y, 0], neighborhood.contains([1, y], 1)))
+            .collect()
+    }
+
+    fn offset_bounds(self, bounds: Option<&PointRange<Self>>) -> ([i128; 3], [i128; 3]) {
+        match bounds {
+            Some(bounds) => {
+                let (start, end, inclusive) = (bounds.start, bounds.end, bounds.end_inclusive);
+                let x = offset_bounds(self.x, start.x, end.x, inclusive);
+                let y = offset_bounds(self.y, start.y, end.y, inclusive);
+                ([x.0, y.0, 0], [x.1, y.1, 0])
+            }
+            None => ([-UNBOUNDED, -UNBOUNDED, 0], [UNBOUNDED, UNBOUNDED, 0]),
+        }
+    }
+
+    fn at(self, offset: [i128; 3]) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_offset(offset[0])?,
+            self.y.checked_offset(offset[1])?,
+        ))
+    }
+}
+
+impl<T: PointRangeIteratorPrimitive, U> FloodFillIteratorItem for euclid::Point3D<T, U> {
+    type Neighborhood = Neighborhood3D;
+
+    fn row_offsets(neighborhood: Self::Neighborhood) -> Vec<([i128; 2], bool)> {
+        let mut offsets = Vec::new();
+        for z in -1..=1 {
+            for y in -1..=1 {
+                if (y, z) != (0, 0) && neighborhood.contains([0, y, z], 1) {
+                    offsets.push(([y, z], neighborhood.contains([1, y, z], 1)));
+                }
+            }
+        }
+        offsets
+    }
+
+    fn offset_bounds(self, bounds: Option<&PointRange<Self>>) -> ([i128; 3], [i128; 3]) {
+        match bounds {
+            Some(bounds) => {
+                let (start, end, inclusive) = (bounds.start, bounds.end, bounds.end_inclusive);
+                let x = offset_bounds(self.x, start.x, end.x, inclusive);
+                let y = offset_bounds(self.y, start.y, end.y, inclusive);
+                let z = offset_bounds(self.z, start.z, end.z, inclusive);
+                ([x.0, y.0, z.0], [x.1, y.1, z.1])
+            }
+            None => ([-UNBOUNDED; 3], [UNBOUNDED; 3]),
+        }
+    }
+
+    fn at(self, offset: [i128; 3]) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_offset(offset[0])?,
+            self.y.checked_offset(offset[1])?,
+            self.z.checked_offset(offset[2])?,
+        ))
+    }
+}
+
+/// Iterates the points connected to a seed through points satisfying a predicate, including the
+/// seed itself if it satisfies the predicate.
+///
+/// The points are found a span along the x axis at a time, and every span yields its points in
+/// order before the next one is searched for. The predicate is called on a point at most a few
+/// times, and never on points outside the bounds.
+#[derive(Clone)]
+pub struct FloodFillIterator<T: FloodFillIteratorItem, F> {
+    seed: T,
+    predicate: F,
+    row_offsets: Vec<([i128; 2], bool)>,
+    min: [i128; 3],
+    max: [i128; 3],
+    /// The spans found so far in each row, keyed by their first offset along the x axis.
+    filled: HashMap<[i128; 2], BTreeMap<i128, i128>>,
+    /// Offsets of points from which to search for spans.
+    stack: Vec<[i128; 3]>,
+    row: [i128; 2],
+    span: ops::Range<i128>,
+}
+
+impl<T: FloodFillIteratorItem, F: FnMut(T) -> bool> FloodFillIterator<T, F> {
+    /// # Examples
+    /// ```
+    /// # use euclid::Point2D;
+    /// # use euclid_ext::Neighborhood2D;
+    /// # use euclid_ext::flood_fill_iterator::FloodFillIterator;
+    /// enum Space {}
+    /// let walls = [Point2D::new(1, -1), Point2D::new(1, 0), Point2D::new(1, 1), Point2D::new(0, 1), Point2D::new(-1, 0)];
+    /// let inside = |p: Point2D<i64, Space>| p.x.abs() <= 1 && p.y.abs() <= 1 && !walls.contains(&p);
+    /// let mut i = FloodFillIterator::new(Point2D::new(0, 0), Neighborhood2D::N4, inside);
+    /// assert_eq!(Some(Point2D::new(0, 0)), i.next());
+    /// assert_eq!(Some(Point2D::new(-1, -1)), i.next());
+    /// assert_eq!(Some(Point2D::new(0, -1)), i.next());
+    /// assert_eq!(None, i.next());
+    /// ```
+    pub fn new(seed: T, neighborhood: T::Neighborhood, predicate: F) -> Self {
+        Self::from_bounds(seed, None, neighborhood, predicate)
+    }
+
+    /// Iterates the points in `bounds` connected to `seed` through points in `bounds`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use euclid::Point3D;
+    /// # use euclid_ext::Neighborhood3D;
+    /// # use euclid_ext::flood_fill_iterator::FloodFillIterator;
+    /// enum Space {}
+    /// let bounds = Point3D::<u8, Space>::new(0, 0, 0)..Point3D::new(3, 3, 3);
+    /// let i = FloodFillIterator::with_bounds(Point3D::new(0, 0, 0), bounds, Neighborhood3D::N18, |p| p.x == p.y);
+    /// assert_eq!(9, i.count());
+    /// ```
+    pub fn with_bounds<B: IntoPointRange<Point = T>>(
+        seed: T,
+        bounds: B,
+        neighborhood: T::Neighborhood,
+        predicate: F,
+    ) -> Self {
+        Self::from_bounds(
+            seed,
+            Some(&bounds.into_point_range()),
+            neighborhood,
+            predicate,
+        )
+    }
+
+    fn from_bounds(
+        seed: T,
+        bounds: Option<&PointRange<T>>,
+        neighborhood: T::Neighborhood,
+        predicate: F,
+    ) -> Self {
+        let (min, max) = seed.offset_bounds(bounds);
+        let in_bounds = (0..3).all(|axis| min[axis] <= 0 && 0 <= max[axis]);
+        Self {
+            seed,
+            predicate,
+            row_offsets: T::row_offsets(neighborhood),
+            min,
+            max,
+            filled: HashMap::new(),
+            stack: if in_bounds { vec![[0; 3]] } else { Vec::new() },
+            row: [0; 2],
+            span: 0..0,
+        }
+    }
+
+    /// Returns the end of the filled span containing `x` in `row`, if any.
+    fn is_filled(&self, row: [i128; 2], x: i128) -> Option<i128> {
+        let spans = self.filled.get(&row)?;
+        let (_, &end) = spans.range(..=x).next_back()?;
+        if x < end {
+            Some(end)
+        } else {
+            None
+        }
+    }
+
+    fn is_fillable(&mut self, row: [i128; 2], x: i128) -> bool {
+        match self.seed.at([x, row[0], row[1]]) {
+            Some(point) => (self.predicate)(point),
+            None => false,
+        }
+    }
+
+    /// Pushes the first point of every run of unfilled points satisfying the predicate in `row`
+    /// from `start` to `end`.
+    fn push_runs(&mut self, row: [i128; 2], start: i128, end: i128) {
+        let mut x = start.max(self.min[0]);
+        let end = end.min(self.max[0] + 1);
+        let mut in_run = false;
+        while x < end {
+            if let Some(filled_end) = self.is_filled(row, x) {
+                in_run = false;
+                x = filled_end;
+                continue;
+            }
+            let fillable = self.is_fillable(row, x);
+            if fillable && !in_run {
+                self.stack.push([x, row[0], row[1]]);
+            }
+            in_run = fillable;
+            x += 1;
+        }
+    }
+
+    /// Fills the span through `seed`, if any, and searches the rows next to it for more.
+    fn fill(&mut self, seed: [i128; 3]) {
+        let (x, row) = (seed[0], [seed[1], seed[2]]);
+        if self.is_filled(row, x).is_some() || !self.is_fillable(row, x) {
+            return;
+        }
+        let (mut start, mut end) = (x, x + 1);
+        while start > self.min[0]
+            && self.is_filled(row, start - 1).is_none()
+            && self.is_fillable(row, start - 1)
+        {
+            start -= 1;
+        }
+        while end <= self.max[0] && self.is_filled(row, end).is_none() && self.is_fillable(row, end)
+        {
+            end += 1;
+        }
+        self.filled.entry(row).or_default().insert(start, end);
+        for index in 0..self.row_offsets.len() {
+            let (offset, diagonal) = self.row_offsets[index];
+            let next = [row[0] + offset[0], row[1] + offset[1]];
+            if (0..2)
+                .all(|axis| self.min[axis + 1] <= next[axis] && next[axis] <= self.max[axis + 1])
+            {
+                let reach = diagonal as i128;
+                self.push_runs(next, start - reach, end + reach);
+            }
+        }
+        self.row = row;
+        self.span = start..end;
+    }
+}
+
+impl<T: FloodFillIteratorItem, F: FnMut(T) -> bool> Iterator for FloodFillIterator<T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.span.start >= self.span.end {
+            let seed = self.stack.pop()?;
+            self.fill(seed);
+        }
+        let x = self.span.start;
+        self.span.start += 1;
+        self.seed.at([x, self.row[0], self.row[1]])
+    }
+}
+
+impl<T: FloodFillIteratorItem, F: FnMut(T) -> bool> iter::FusedIterator
+    for FloodFillIterator<T, F>
+{
+}
+
+impl<T: FloodFillIteratorItem + fmt::Debug, F> fmt::Debug for FloodFillIterator<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FloodFillIterator")
+            .field("seed", &self.seed)
+            .field("stack", &self.stack)
+            .field("row", &self.row)
+            .field("span", &self.span)
+            .finish()
+    }
+}
@@ -0,0 +1,306 @@
+use crate::grid_layout::{GridLayout2D, GridLayout3D};
+use crate::grid_view::{GridView2D, GridViewMut2D};
+use crate::point_range_iterator::PointRangeIterator;
+use std::{fmt, hash, iter, ops, slice};
+
+macro_rules! impl_grid {
+    ($grid:ident, $layout:ident, $box:ident, $point:ident) => {
+        impl<T, U> $grid<T, U> {
+            /// Makes a grid over `bounds` with every cell set to `value`.
+            ///
+            /// # Panics
+            /// Panics if `bounds` has more than `usize::MAX` cells.
+            pub fn new(bounds: euclid::$box<i32, U>, value: T) -> Self
+            where
+                T: Clone,
+            {
+                let layout = $layout::new(bounds);
+                Self {
+                    layout,
+                    cells: vec![value; layout.len()],
+                }
+            }
+
+            /// Makes a grid over `bounds` with each cell set to `f` of its point, called in the
+            /// order of `points`.
+            pub fn from_fn(
+                bounds: euclid::$box<i32, U>,
+                f: impl FnMut(euclid::$point<i32, U>) -> T,
+            ) -> Self {
+                let layout = $layout::new(bounds);
+                let cells = layout.points().map(f).collect();
+                Self { layout, cells }
+            }
+
+            /// Makes a grid over `bounds` from its cells in the order of `points`.
+            ///
+            /// # Panics
+            /// Panics if `cells` does not have one element per cell.
+            pub fn from_vec(bounds: euclid::$box<i32, U>, cells: Vec<T>) -> Self {
+                let layout = $layout::new(bounds);
+                assert_eq!(layout.len(), cells.len(), "grid needs one element per cell");
+                Self { layout, cells }
+            }
+
+            pub fn bounds(&self) -> euclid::$box<i32, U> {
+                self.layout.bounds()
+            }
+
+            pub fn len(&self) -> usize {
+                self.cells.len()
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.cells.is_empty()
+            }
+
+            pub fn contains(&self, point: euclid::$point<i32, U>) -> bool {
+                self.index_of(point).is_some()
+            }
+
+            /// Returns the layout of the cells in `as_slice`.
+            pub fn layout(&self) -> $layout<U> {
+                self.layout
+            }
+
+            /// Returns the index in `as_slice` of the cell at `point`.
+            pub fn index_of(&self, point: euclid::$point<i32, U>) -> Option<usize> {
+                self.layout.index_of(point)
+            }
+
+            pub fn get(&self, point: euclid::$point<i32, U>) -> Option<&T> {
+                let index = self.index_of(point)?;
+                self.cells.get(index)
+            }
+
+            pub fn get_mut(&mut self, point: euclid::$point<i32, U>) -> Option<&mut T> {
+                let index = self.index_of(point)?;
+                self.cells.get_mut(index)
+            }
+
+            /// Iterates the points of the cells in the order of `Points` over the bounds.
+            pub fn points(&self) -> PointRangeIterator<euclid::$point<i32, U>> {
+                self.layout.points()
+            }
+
+            /// Iterates the cells with their points in the order of `points`.
+            pub fn iter(
+                &self,
+            ) -> iter::Zip<PointRangeIterator<euclid::$point<i32, U>>, slice::Iter<'_, T>> {
+                self.points().zip(self.cells.iter())
+            }
+
+            /// Iterates the cells mutably with their points in the order of `points`.
+            pub fn iter_mut(
+                &mut self,
+            ) -> iter::Zip<PointRangeIterator<euclid::$point<i32, U>>, slice::IterMut<'_, T>> {
+                self.points().zip(self.cells.iter_mut())
+            }
+
+            /// Returns the cells in the order of `points`.
+            pub fn as_slice(&self) -> &[T] {
+                &self.cells
+            }
+
+            pub fn as_mut_slice(&mut self) -> &mut [T] {
+                &mut self.cells
+            }
+
+            pub fn into_vec(self) -> Vec<T> {
+                self.cells
+            }
+        }
+
+        impl<T, U> ops::Index<euclid::$point<i32, U>> for $grid<T, U> {
+            type Output = T;
+
+            /// # Panics
+            /// Panics if `point` is out of the bounds.
+            fn index(&self, point: euclid::$point<i32, U>) -> &T {
+                match self.get(point) {
+                    Some(cell) => cell,
+                    None => panic!("point {:?} is out of the grid bounds", point.to_array()),
+                }
+            }
+        }
+
+        impl<T, U> ops::IndexMut<euclid::$point<i32, U>> for $grid<T, U> {
+            /// # Panics
+            /// Panics if `point` is out of the bounds.
+            fn index_mut(&mut self, point: euclid::$point<i32, U>) -> &mut T {
+                match self.index_of(point) {
+                    Some(index) => &mut self.cells[index],
+                    None => panic!("point {:?} is out of the grid bounds", point.to_array()),
+                }
+            }
+        }
+
+        impl<'a, T, U> IntoIterator for &'a $grid<T, U> {
+            type Item = (euclid::$point<i32, U>, &'a T);
+
+            type IntoIter =
+                iter::Zip<PointRangeIterator<euclid::$point<i32, U>>, slice::Iter<'a, T>>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'a, T, U> IntoIterator for &'a mut $grid<T, U> {
+            type Item = (euclid::$point<i32, U>, &'a mut T);
+
+            type IntoIter =
+                iter::Zip<PointRangeIterator<euclid::$point<i32, U>>, slice::IterMut<'a, T>>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+
+        // Implemented by hand so that they are not bounded on the unit.
+        impl<T: Clone, U> Clone for $grid<T, U> {
+            fn clone(&self) -> Self {
+                Self {
+                    layout: self.layout,
+                    cells: self.cells.clone(),
+                }
+            }
+        }
+
+        impl<T: fmt::Debug, U> fmt::Debug for $grid<T, U> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct(stringify!($grid))
+                    .field("bounds", &self.bounds())
+                    .field("cells", &self.cells)
+                    .finish()
+            }
+        }
+
+        impl<T: PartialEq, U> PartialEq for $grid<T, U> {
+            fn eq(&self, other: &Self) -> bool {
+                self.layout == other.layout && self.cells == other.cells
+            }
+        }
+
+        impl<T: Eq, U> Eq for $grid<T, U> {}
+
+        impl<T: hash::Hash, U> hash::Hash for $grid<T, U> {
+            fn hash<H: hash::Hasher>(&self, state: &mut H) {
+                self.layout.hash(state);
+                self.cells.hash(state);
+            }
+        }
+    };
+}
+
+/// A dense grid of cells over a box, stored in the order of `Points` over the box.
+///
+/// # Examples
+/// ```
+/// # use euclid::{Box2D, Point2D};
+/// # use euclid_ext::Grid2D;
+/// enum Space {}
+/// let bounds = Box2D::new(Point2D::<i32, Space>::new(-2, -1), Point2D::new(2, 1));
+/// let mut grid = Grid2D::new(bounds, 0);
+/// grid[Point2D::new(-2, -1)] = 1;
+/// *grid.get_mut(Point2D::new(1, 0)).unwrap() = 2;
+/// assert_eq!(None, grid.get(Point2D::new(2, 0)));
+/// assert_eq!(&[1, 0, 0, 0, 0, 0, 0, 2], grid.as_slice());
+/// assert_eq!(
+///     Some((Point2D::new(1, 0), &2)),
+///     grid.iter().last());
+/// ```
+pub struct Grid2D<T, U> {
+    /// The layout of `cells` over the bounds.
+    layout: GridLayout2D<U>,
+    cells: Vec<T>,
+}
+
+impl_grid!(Grid2D, GridLayout2D, Box2D, Point2D);
+
+impl<T, U> Grid2D<T, U> {
+    pub fn as_view(&self) -> GridView2D<'_, T, U> {
+        self.view(self.bounds())
+    }
+
+    pub fn as_view_mut(&mut self) -> GridViewMut2D<'_, T, U> {
+        self.view_mut(self.bounds())
+    }
+
+    /// Makes a view of the cells in `bounds`.
+    ///
+    /// # Panics
+    /// Panics if `bounds` is not empty and not contained in the bounds of the grid.
+    pub fn view(&self, bounds: euclid::Box2D<i32, U>) -> GridView2D<'_, T, U> {
+        GridView2D::new(self.bounds(), &self.cells, bounds)
+    }
+
+    /// Makes a mutable view of the cells in `bounds`.
+    ///
+    /// # Panics
+    /// Panics if `bounds` is not empty and not contained in the bounds of the grid.
+    pub fn view_mut(&mut self, bounds: euclid::Box2D<i32, U>) -> GridViewMut2D<'_, T, U> {
+        GridViewMut2D::new(self.bounds(), &mut self.cells, bounds)
+    }
+
+    /// Splits the grid into disjoint mutable views of the tiles of `size` covering it, in the
+    /// order of `Tiles::tiles` over the bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use euclid::{Box2D, Point2D, Size2D};
+    /// # use euclid_ext::Grid2D;
+    /// enum Space {}
+    /// let bounds = Box2D::new(Point2D::<i32, Space>::new(0, 0), Point2D::new(5, 2));
+    /// let mut grid = Grid2D::new(bounds, 0);
+    /// for (index, mut tile) in grid.tiles_mut(Size2D::new(2, 2)).into_iter().enumerate() {
+    ///     tile.fill(index);
+    /// }
+    /// assert_eq!(&[0, 0, 1, 1, 2, 0, 0, 1, 1, 2], grid.as_slice());
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `size` is not positive along every axis.
+    pub fn tiles_mut(&mut self, size: euclid::Size2D<i32, U>) -> Vec<GridViewMut2D<'_, T, U>> {
+        assert!(
+            size.width > 0 && size.height > 0,
+            "tile size must be positive"
+        );
+        let (min, max) = (self.bounds().min, self.bounds().max);
+        let mut tiles = Vec::new();
+        let mut rest = self.as_view_mut();
+        for y in (i64::from(min.y)..i64::from(max.y)).step_by(size.height as usize) {
+            let end = (y + i64::from(size.height)).min(i64::from(max.y)) as i32;
+            let (mut row, below) = rest.split_at_y(end);
+            rest = below;
+            for x in (i64::from(min.x)..i64::from(max.x)).step_by(size.width as usize) {
+                let end = (x + i64::from(size.width)).min(i64::from(max.x)) as i32;
+                let (tile, right) = row.split_at_x(end);
+                row = right;
+                tiles.push(tile);
+            }
+        }
+        tiles
+    }
+}
+
+/// A dense grid of cells over a box, stored in the order of `Points` over the box.
+///
+/// # Examples
+/// ```
+/// # use euclid::{Box3D, Point3D};
+/// # use euclid_ext::Grid3D;
+/// enum Space {}
+/// let bounds = Box3D::new(Point3D::<i32, Space>::new(0, 0, -3), Point3D::new(2, 2, 0));
+/// let grid = Grid3D::from_fn(bounds, |p| p.x + p.y * 10 + p.z * 100);
+/// assert_eq!(12, grid.len());
+/// assert_eq!(-189, grid[Point3D::new(1, 1, -2)]);
+/// assert_eq!(Some(7), grid.index_of(Point3D::new(1, 1, -2)));
+/// ```
+pub struct Grid3D<T, U> {
+    /// The layout of `cells` over the bounds.
+    layout: GridLayout3D<U>,
+    cells: Vec<T>,
+}
+
+impl_grid!(Grid3D, GridLayout3D, Box3D, Point3D);
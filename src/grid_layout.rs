@@ -0,0 +1,277 @@
+use crate::order::{Order2D, Order3D};
+use crate::point_range_iterator::PointRangeIterator;
+use crate::points::Points;
+use std::{fmt, hash};
+
+macro_rules! impl_grid_layout {
+    ($layout:ident, $box:ident, $point:ident, $size:ident, $order:ident, $dimensions:literal) => {
+        impl<U> $layout<U> {
+            /// Makes a layout of the points in `bounds` in row-major order.
+            ///
+            /// # Panics
+            /// Panics if `bounds` has more than `usize::MAX` points.
+            pub fn new(bounds: euclid::$box<i32, U>) -> Self {
+                Self::with_order(bounds, $order::default())
+            }
+
+            /// Makes a layout of the points in a box of `size` from the origin in row-major order.
+            ///
+            /// # Panics
+            /// Panics if the box has more than `usize::MAX` points.
+            pub fn from_size(size: euclid::$size<i32, U>) -> Self {
+                Self::new(euclid::$box::from_size(size))
+            }
+
+            /// Makes a layout of the points in `bounds` in the order of `PointRangeIterator` in
+            /// `order` over them.
+            ///
+            /// # Panics
+            /// Panics if `bounds` has more than `usize::MAX` points.
+            pub fn with_order(bounds: euclid::$box<i32, U>, order: $order) -> Self {
+                let counts = Self::counts(&bounds);
+                let mut strides = [0; $dimensions];
+                let mut stride = 1usize;
+                for &axis in order.axes().iter() {
+                    strides[axis] = stride;
+                    stride = stride
+                        .checked_mul(counts[axis])
+                        .expect("layout has more than usize::MAX points");
+                }
+                Self {
+                    bounds,
+                    strides,
+                    order,
+                    buffer_len: stride,
+                }
+            }
+
+            /// Makes a layout of the points in `bounds` with the index advancing by `strides`
+            /// per step along each axis, such as to leave padding at the end of each row.
+            ///
+            /// The strides need to nest: taken from the smallest, each needs to be greater than
+            /// the largest index reached by stepping along the axes with the smaller ones. This
+            /// excludes some layouts where no two points have the same index, such as strides of
+            /// `[2, 3]` over 3 by 2 points.
+            ///
+            /// # Panics
+            /// Panics if the strides do not nest, or if an index would be greater than
+            /// `usize::MAX`.
+            pub fn with_strides(
+                bounds: euclid::$box<i32, U>,
+                strides: [usize; $dimensions],
+            ) -> Self {
+                let counts = Self::counts(&bounds);
+                let mut axes = [0; $dimensions];
+                for (axis, slot) in axes.iter_mut().enumerate() {
+                    *slot = axis;
+                }
+                axes.sort_by_key(|&axis| strides[axis]);
+                let mut last = 0usize;
+                if counts.iter().all(|&count| count > 0) {
+                    for &axis in axes.iter().filter(|&&axis| counts[axis] > 1) {
+                        assert!(strides[axis] > last, "strides do not nest");
+                        last = (counts[axis] - 1)
+                            .checked_mul(strides[axis])
+                            .and_then(|offset| offset.checked_add(last))
+                            .filter(|&last| last < usize::MAX)
+                            .expect("layout has indices greater than usize::MAX");
+                    }
+                }
+                let buffer_len = if counts.iter().all(|&count| count > 0) {
+                    last + 1
+                } else {
+                    0
+                };
+                Self {
+                    bounds,
+                    strides,
+                    order: $order::from_axes(axes),
+                    buffer_len,
+                }
+            }
+
+            /// Returns the number of points along each axis.
+            fn counts(bounds: &euclid::$box<i32, U>) -> [usize; $dimensions] {
+                let (min, max) = (bounds.min.to_array(), bounds.max.to_array());
+                let mut counts = [0; $dimensions];
+                for axis in 0..$dimensions {
+                    counts[axis] = (i64::from(max[axis]) - i64::from(min[axis])).max(0) as usize;
+                }
+                counts
+            }
+
+            pub fn bounds(&self) -> euclid::$box<i32, U> {
+                self.bounds
+            }
+
+            /// Returns how much the index advances per step along each axis.
+            pub fn strides(&self) -> [usize; $dimensions] {
+                self.strides
+            }
+
+            /// Returns the order of the axes from the smallest stride to the largest.
+            pub fn order(&self) -> $order {
+                self.order
+            }
+
+            /// Returns the number of points.
+            pub fn len(&self) -> usize {
+                Self::counts(&self.bounds).iter().product()
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Returns the length of the buffers storing an element per point, including the
+            /// padding between them.
+            pub fn buffer_len(&self) -> usize {
+                self.buffer_len
+            }
+
+            pub fn contains(&self, point: euclid::$point<i32, U>) -> bool {
+                self.bounds.contains(point)
+            }
+
+            /// Returns the index of `point`, or `None` if it is out of the bounds.
+            pub fn index_of(&self, point: euclid::$point<i32, U>) -> Option<usize> {
+                if !self.bounds.contains(point) {
+                    return None;
+                }
+                let (point, min) = (point.to_array(), self.bounds.min.to_array());
+                let mut index = 0;
+                for axis in 0..$dimensions {
+                    let offset = (i64::from(point[axis]) - i64::from(min[axis])) as usize;
+                    index += offset * self.strides[axis];
+                }
+                Some(index)
+            }
+
+            /// Returns the point at `index`, or `None` if no point has it.
+            ///
+            /// Since the strides nest, the offset along each axis is the index left by the axes
+            /// with larger strides divided by its stride.
+            pub fn point_at(&self, index: usize) -> Option<euclid::$point<i32, U>> {
+                if index >= self.buffer_len {
+                    return None;
+                }
+                let (counts, min) = (Self::counts(&self.bounds), self.bounds.min.to_array());
+                let mut point = [0; $dimensions];
+                let mut rest = index;
+                for &axis in self.order.axes().iter().rev() {
+                    let offset = if counts[axis] > 1 {
+                        rest / self.strides[axis]
+                    } else {
+                        0
+                    };
+                    if offset >= counts[axis] {
+                        return None;
+                    }
+                    rest -= offset * self.strides[axis];
+                    point[axis] = (i64::from(min[axis]) + offset as i64) as i32;
+                }
+                if rest == 0 {
+                    Some(point.into())
+                } else {
+                    None
+                }
+            }
+
+            /// Iterates the points in the order of their indices.
+            pub fn points(&self) -> PointRangeIterator<euclid::$point<i32, U>> {
+                self.bounds.points_in_order(self.order)
+            }
+        }
+
+        // Implemented by hand so that they are not bounded on the unit.
+        impl<U> Clone for $layout<U> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<U> Copy for $layout<U> {}
+
+        impl<U> fmt::Debug for $layout<U> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct(stringify!($layout))
+                    .field("bounds", &self.bounds)
+                    .field("strides", &self.strides)
+                    .field("order", &self.order)
+                    .finish()
+            }
+        }
+
+        impl<U> PartialEq for $layout<U> {
+            fn eq(&self, other: &Self) -> bool {
+                self.bounds == other.bounds && self.strides == other.strides
+            }
+        }
+
+        impl<U> Eq for $layout<U> {}
+
+        impl<U> hash::Hash for $layout<U> {
+            fn hash<H: hash::Hasher>(&self, state: &mut H) {
+                self.bounds.hash(state);
+                self.strides.hash(state);
+            }
+        }
+    };
+}
+
+/// Converts between the points in a box and the indices of a flat buffer storing an element per
+/// point.
+///
+/// # Examples
+/// ```
+/// # use euclid::{Box2D, Point2D, Size2D};
+/// # use euclid_ext::{GridLayout2D, Order2D};
+/// enum Space {}
+/// let layout = GridLayout2D::from_size(Size2D::<i32, Space>::new(4, 3));
+/// assert_eq!(Some(9), layout.index_of(Point2D::new(1, 2)));
+/// assert_eq!(Some(Point2D::new(1, 2)), layout.point_at(9));
+/// assert_eq!(None, layout.index_of(Point2D::new(4, 0)));
+/// assert_eq!(None, layout.point_at(12));
+///
+/// let bounds = Box2D::new(Point2D::<i32, Space>::new(-1, -1), Point2D::new(1, 2));
+/// let layout = GridLayout2D::with_order(bounds, Order2D::YX);
+/// assert_eq!(Some(4), layout.index_of(Point2D::new(0, 0)));
+///
+/// let padded = GridLayout2D::with_strides(bounds, [1, 4]);
+/// assert_eq!(Some(5), padded.index_of(Point2D::new(0, 0)));
+/// assert_eq!(None, padded.point_at(2));
+/// assert_eq!(10, padded.buffer_len());
+/// ```
+pub struct GridLayout2D<U> {
+    bounds: euclid::Box2D<i32, U>,
+    strides: [usize; 2],
+    order: Order2D,
+    buffer_len: usize,
+}
+
+impl_grid_layout!(GridLayout2D, Box2D, Point2D, Size2D, Order2D, 2);
+
+/// Converts between the points in a box and the indices of a flat buffer storing an element per
+/// point.
+///
+/// # Examples
+/// ```
+/// # use euclid::{Point3D, Size3D};
+/// # use euclid_ext::{GridLayout3D, Order3D};
+/// enum Space {}
+/// let size = Size3D::<i32, Space>::new(4, 3, 2);
+/// let layout = GridLayout3D::from_size(size);
+/// assert_eq!([1, 4, 12], layout.strides());
+/// assert_eq!(Some(21), layout.index_of(Point3D::new(1, 2, 1)));
+/// let layout = GridLayout3D::with_order(layout.bounds(), Order3D::ZYX);
+/// assert_eq!([6, 2, 1], layout.strides());
+/// assert_eq!(Some(Point3D::new(1, 2, 1)), layout.point_at(11));
+/// ```
+pub struct GridLayout3D<U> {
+    bounds: euclid::Box3D<i32, U>,
+    strides: [usize; 3],
+    order: Order3D,
+    buffer_len: usize,
+}
+
+impl_grid_layout!(GridLayout3D, Box3D, Point3D, Size3D, Order3D, 3);
@@ -0,0 +1,266 @@
+use crate::grid::{Grid2D, Grid3D};
+use crate::map::{Map2D, Map3D};
+
+/// Grows the bounds `(low, high)` along an axis by `min` before and `max` after them.
+fn pad_axis<U>(
+    (low, high): (euclid::Length<i32, U>, euclid::Length<i32, U>),
+    min: euclid::Length<i32, U>,
+    max: euclid::Length<i32, U>,
+) -> (euclid::Length<i32, U>, euclid::Length<i32, U>) {
+    match (low.0.checked_sub(min.0), high.0.checked_add(max.0)) {
+        (Some(low), Some(high)) => (euclid::Length::new(low), euclid::Length::new(high)),
+        _ => panic!("padded bounds are out of the range of i32"),
+    }
+}
+
+macro_rules! impl_transform {
+    ($grid:ident, $box:ident, $point:ident, $dimensions:literal) => {
+        impl<T: Clone, U> $grid<T, U> {
+            /// Makes a grid with the coordinates of each point along each axis taken from those
+            /// along `axes`, mirrored around the origin along the axes in `flips`.
+            ///
+            /// # Panics
+            /// Panics if the bounds start at `i32::MIN` along an axis which is mirrored, since
+            /// they would end at `-i32::MIN`.
+            fn transform(&self, axes: [usize; $dimensions], flips: [bool; $dimensions]) -> Self {
+                // Cell edges are mirrored to their negations, and cells to the cells before them.
+                // Mirroring swaps the ends of the bounds, which keeps empty bounds empty.
+                let (min, max) = (self.bounds().min.to_array(), self.bounds().max.to_array());
+                let (mut start, mut end) = ([0; $dimensions], [0; $dimensions]);
+                for axis in 0..$dimensions {
+                    let (min, max) = (min[axes[axis]], max[axes[axis]]);
+                    if flips[axis] {
+                        let mirror = |edge: i32| {
+                            edge.checked_neg()
+                                .expect("mirrored bounds are out of the range of i32")
+                        };
+                        start[axis] = mirror(max);
+                        end[axis] = mirror(min);
+                    } else {
+                        start[axis] = min;
+                        end[axis] = max;
+                    }
+                }
+                let bounds = euclid::$box::new(start.into(), end.into());
+                Self::from_fn(bounds, |point| {
+                    let point = point.to_array();
+                    let mut source = [0; $dimensions];
+                    for axis in 0..$dimensions {
+                        let coordinate = point[axis];
+                        source[axes[axis]] = if flips[axis] { !coordinate } else { coordinate };
+                    }
+                    self[euclid::$point::from(source)].clone()
+                })
+            }
+
+            /// Makes a grid of the cells in the intersection of the bounds with `bounds`.
+            pub fn crop(&self, bounds: euclid::$box<i32, U>) -> Self {
+                Self::from_fn(self.bounds().intersection(&bounds), |point| {
+                    self[point].clone()
+                })
+            }
+
+            /// Makes a grid over `bounds` with the cells at the same points, and the other cells
+            /// set to `value`.
+            pub fn resize(&self, bounds: euclid::$box<i32, U>, value: T) -> Self {
+                Self::from_fn(bounds, |point| match self.get(point) {
+                    Some(cell) => cell.clone(),
+                    None => value.clone(),
+                })
+            }
+        }
+    };
+}
+
+impl_transform!(Grid2D, Box2D, Point2D, 2);
+
+impl_transform!(Grid3D, Box3D, Point3D, 3);
+
+/// The transforms rotate and mirror the cells as unit squares around the origin, and the bounds
+/// move along with them. For example, mirroring along the x axis moves the cell at `x` to
+/// `-x - 1`.
+///
+/// # Examples
+/// ```
+/// # use euclid::{Box2D, Point2D};
+/// # use euclid_ext::Grid2D;
+/// enum Space {}
+/// let bounds = Box2D::new(Point2D::<i32, Space>::new(1, 0), Point2D::new(4, 2));
+/// let grid = Grid2D::from_vec(bounds, vec![1, 2, 3, 4, 5, 6]);
+/// let rotated = grid.rotate_90();
+/// assert_eq!(Box2D::new(Point2D::new(-2, 1), Point2D::new(0, 4)), rotated.bounds());
+/// assert_eq!(&[4, 1, 5, 2, 6, 3], rotated.as_slice());
+/// assert_eq!(&[3, 2, 1, 6, 5, 4], grid.flip_x().as_slice());
+/// assert_eq!(grid.rotate_90(), grid.transpose().flip_x());
+/// ```
+impl<T: Clone, U> Grid2D<T, U> {
+    /// Rotates the grid a quarter turn, turning the x axis into the y axis.
+    ///
+    /// # Panics
+    /// Panics if the bounds start at `i32::MIN` along the y axis.
+    pub fn rotate_90(&self) -> Self {
+        self.transform([1, 0], [true, false])
+    }
+
+    /// # Panics
+    /// Panics if the bounds start at `i32::MIN` along either the x or the y axis.
+    pub fn rotate_180(&self) -> Self {
+        self.transform([0, 1], [true, true])
+    }
+
+    /// Rotates the grid a quarter turn, turning the y axis into the x axis.
+    ///
+    /// # Panics
+    /// Panics if the bounds start at `i32::MIN` along the x axis.
+    pub fn rotate_270(&self) -> Self {
+        self.transform([1, 0], [false, true])
+    }
+
+    /// Mirrors the grid along the x axis.
+    ///
+    /// # Panics
+    /// Panics if the bounds start at `i32::MIN` along the x axis.
+    pub fn flip_x(&self) -> Self {
+        self.transform([0, 1], [true, false])
+    }
+
+    /// Mirrors the grid along the y axis.
+    ///
+    /// # Panics
+    /// Panics if the bounds start at `i32::MIN` along the y axis.
+    pub fn flip_y(&self) -> Self {
+        self.transform([0, 1], [false, true])
+    }
+
+    /// Swaps the x and y axes of the grid.
+    pub fn transpose(&self) -> Self {
+        self.transform([1, 0], [false, false])
+    }
+
+    /// Makes a grid over the bounds grown by `offsets`, with the cells out of the bounds set to
+    /// `value`.
+    ///
+    /// # Panics
+    /// Panics if the grown bounds are out of the range of `i32`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use euclid::{Box2D, Point2D, SideOffsets2D};
+    /// # use euclid_ext::Grid2D;
+    /// enum Space {}
+    /// let grid = Grid2D::new(Box2D::new(Point2D::<i32, Space>::new(0, 0), Point2D::new(2, 1)), 1);
+    /// let padded = grid.pad(SideOffsets2D::new(1, 0, 0, 1), 0);
+    /// assert_eq!(Box2D::new(Point2D::new(-1, -1), Point2D::new(2, 1)), padded.bounds());
+    /// assert_eq!(&[0, 0, 0, 0, 1, 1], padded.as_slice());
+    /// ```
+    pub fn pad(&self, offsets: euclid::SideOffsets2D<i32, U>, value: T) -> Self {
+        let min = euclid::Size2D::<i32, U>::new(offsets.left, offsets.top);
+        let max = euclid::Size2D::<i32, U>::new(offsets.right, offsets.bottom);
+        self.resize(
+            (self.bounds(), min, max).map(|(bounds, min, max)| pad_axis(bounds, min, max)),
+            value,
+        )
+    }
+}
+
+/// The transforms rotate and mirror the cells as unit cubes around the origin, and the bounds move
+/// along with them. For example, mirroring along the x axis moves the cell at `x` to `-x - 1`.
+///
+/// # Examples
+/// ```
+/// # use euclid::{Box3D, Point3D};
+/// # use euclid_ext::Grid3D;
+/// enum Space {}
+/// let bounds = Box3D::new(Point3D::<i32, Space>::new(0, 0, 0), Point3D::new(3, 2, 1));
+/// let grid = Grid3D::from_fn(bounds, |p| p);
+/// let rotated = grid.rotate_90_x();
+/// assert_eq!(Box3D::new(Point3D::new(0, -1, 0), Point3D::new(3, 0, 2)), rotated.bounds());
+/// assert_eq!(Point3D::new(2, 1, 0), rotated[Point3D::new(2, -1, 1)]);
+/// ```
+impl<T: Clone, U> Grid3D<T, U> {
+    /// Rotates the grid a quarter turn around the x axis, turning the y axis into the z axis.
+    ///
+    /// # Panics
+    /// Panics if the bounds start at `i32::MIN` along the z axis.
+    pub fn rotate_90_x(&self) -> Self {
+        self.transform([0, 2, 1], [false, true, false])
+    }
+
+    /// Rotates the grid a quarter turn around the y axis, turning the z axis into the x axis.
+    ///
+    /// # Panics
+    /// Panics if the bounds start at `i32::MIN` along the x axis.
+    pub fn rotate_90_y(&self) -> Self {
+        self.transform([2, 1, 0], [false, false, true])
+    }
+
+    /// Rotates the grid a quarter turn around the z axis, turning the x axis into the y axis.
+    ///
+    /// # Panics
+    /// Panics if the bounds start at `i32::MIN` along the y axis.
+    pub fn rotate_90_z(&self) -> Self {
+        self.transform([1, 0, 2], [true, false, false])
+    }
+
+    /// Mirrors the grid along the x axis.
+    ///
+    /// # Panics
+    /// Panics if the bounds start at `i32::MIN` along the x axis.
+    pub fn flip_x(&self) -> Self {
+        self.transform([0, 1, 2], [true, false, false])
+    }
+
+    /// Mirrors the grid along the y axis.
+    ///
+    /// # Panics
+    /// Panics if the bounds start at `i32::MIN` along the y axis.
+    pub fn flip_y(&self) -> Self {
+        self.transform([0, 1, 2], [false, true, false])
+    }
+
+    /// Mirrors the grid along the z axis.
+    ///
+    /// # Panics
+    /// Panics if the bounds start at `i32::MIN` along the z axis.
+    pub fn flip_z(&self) -> Self {
+        self.transform([0, 1, 2], [false, false, true])
+    }
+
+    /// Swaps the x and y axes of the grid.
+    pub fn transpose_xy(&self) -> Self {
+        self.transform([1, 0, 2], [false; 3])
+    }
+
+    /// Swaps the x and z axes of the grid.
+    pub fn transpose_xz(&self) -> Self {
+        self.transform([2, 1, 0], [false; 3])
+    }
+
+    /// Swaps the y and z axes of the grid.
+    pub fn transpose_yz(&self) -> Self {
+        self.transform([0, 2, 1], [false; 3])
+    }
+
+    /// Makes a grid over the bounds grown by `min` before and `max` after the cells along each
+    /// axis, with the cells out of the bounds set to `value`.
+    ///
+    /// # Panics
+    /// Panics if the grown bounds are out of the range of `i32`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use euclid::{Box3D, Point3D, Size3D};
+    /// # use euclid_ext::Grid3D;
+    /// enum Space {}
+    /// let grid = Grid3D::new(Box3D::new(Point3D::<i32, Space>::new(0, 0, 0), Point3D::new(1, 1, 1)), 1);
+    /// let padded = grid.pad(Size3D::new(1, 0, 0), Size3D::new(0, 0, 1), 0);
+    /// assert_eq!(Box3D::new(Point3D::new(-1, 0, 0), Point3D::new(1, 1, 2)), padded.bounds());
+    /// assert_eq!(&[0, 1, 0, 0], padded.as_slice());
+    /// ```
+    pub fn pad(&self, min: euclid::Size3D<i32, U>, max: euclid::Size3D<i32, U>, value: T) -> Self {
+        self.resize(
+            (self.bounds(), min, max).map(|(bounds, min, max)| pad_axis(bounds, min, max)),
+            value,
+        )
+    }
+}
@@ -0,0 +1,626 @@
+use crate::grid::Grid2D;
+use crate::point_range_iterator::PointRangeIterator;
+use crate::points::Points;
+use euclid::{Box2D, Point2D};
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+use std::{fmt, ops, slice};
+
+/// Returns the number of cells from `min` to `max`, or zero if `max` is not greater.
+fn extent(min: i32, max: i32) -> usize {
+    (i64::from(max) - i64::from(min)).max(0) as usize
+}
+
+/// Returns the offsets of `point` from the minimum of `bounds` along each axis, if it is in them.
+fn offset_in<U>(bounds: &Box2D<i32, U>, point: Point2D<i32, U>) -> Option<(usize, usize)> {
+    if bounds.contains(point) {
+        Some((extent(bounds.min.x, point.x), extent(bounds.min.y, point.y)))
+    } else {
+        None
+    }
+}
+
+/// Returns the offsets of the cell at `local` in a view of `size` cells along each axis.
+fn local_offset<U>(size: (usize, usize), local: Point2D<i32, U>) -> Option<(usize, usize)> {
+    if local.x < 0 || local.y < 0 {
+        return None;
+    }
+    let (x, y) = (local.x as usize, local.y as usize);
+    if x < size.0 && y < size.1 {
+        Some((x, y))
+    } else {
+        None
+    }
+}
+
+/// Returns the offsets of `view` in `bounds` along each axis and its size, all zero if it is empty.
+///
+/// # Panics
+/// Panics if `view` is not empty and not contained in `bounds`.
+fn sub_view<U>(bounds: &Box2D<i32, U>, view: &Box2D<i32, U>) -> ((usize, usize), (usize, usize)) {
+    assert!(
+        bounds.contains_box(view),
+        "view bounds {:?} are out of the bounds {:?}",
+        view.to_untyped(),
+        bounds.to_untyped()
+    );
+    let size = (
+        extent(view.min.x, view.max.x),
+        extent(view.min.y, view.max.y),
+    );
+    if size.0 == 0 || size.1 == 0 {
+        return ((0, 0), (0, 0));
+    }
+    let offset = (
+        extent(bounds.min.x, view.min.x),
+        extent(bounds.min.y, view.min.y),
+    );
+    (offset, size)
+}
+
+/// The cells of a view, `width` cells in each of `height` rows, with the rows starting `stride`
+/// cells apart from `first`.
+///
+/// It points into the cells of a grid which the view borrows, sharing them if the view is shared,
+/// or keeping them from any other view if it is mutable. Splitting leaves the rows interleaved in
+/// memory, so it cannot be a slice.
+struct Cells<T> {
+    first: NonNull<T>,
+    stride: usize,
+    width: usize,
+    height: usize,
+}
+
+// The views carry the bounds on `T` with markers of the references they stand for.
+unsafe impl<T> Send for Cells<T> {}
+
+unsafe impl<T> Sync for Cells<T> {}
+
+impl<T> Clone for Cells<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Cells<T> {}
+
+impl<T> Cells<T> {
+    /// Makes the cells of a grid `width` cells wide storing the `len` cells from `first` in the
+    /// order of `Points`.
+    fn new(first: NonNull<T>, len: usize, width: usize) -> Self {
+        let empty = len == 0 || width == 0;
+        Self {
+            first,
+            stride: width,
+            width: if empty { 0 } else { width },
+            height: if empty { 0 } else { len / width },
+        }
+    }
+
+    fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    fn len(&self) -> usize {
+        self.width * self.height
+    }
+
+    /// Returns the cells of size `size` from `offset`, which need to be within these cells
+    /// unless the size is zero.
+    fn sub(&self, offset: (usize, usize), size: (usize, usize)) -> Self {
+        if size.0 == 0 || size.1 == 0 {
+            return Self {
+                width: 0,
+                height: 0,
+                ..*self
+            };
+        }
+        debug_assert!(offset.0 + size.0 <= self.width && offset.1 + size.1 <= self.height);
+        Self {
+            // Within the rows, since the offset is.
+            first: unsafe {
+                NonNull::new_unchecked(self.first.as_ptr().add(offset.1 * self.stride + offset.0))
+            },
+            stride: self.stride,
+            width: size.0,
+            height: size.1,
+        }
+    }
+
+    /// Returns the index of the cell at `offset`, or `None` if it is out of these cells.
+    fn index(&self, offset: (usize, usize)) -> Option<usize> {
+        if offset.0 < self.width && offset.1 < self.height {
+            Some(offset.1 * self.stride + offset.0)
+        } else {
+            None
+        }
+    }
+
+    /// # Safety
+    /// The view of these cells needs to be borrowed for `'a`, and `y` less than the height.
+    unsafe fn row<'a>(&self, y: usize) -> &'a [T] {
+        slice::from_raw_parts(self.first.as_ptr().add(y * self.stride), self.width)
+    }
+
+    /// # Safety
+    /// The mutable view of these cells needs to be borrowed mutably for `'a`, and `y` less than
+    /// the height.
+    unsafe fn row_mut<'a>(&self, y: usize) -> &'a mut [T] {
+        slice::from_raw_parts_mut(self.first.as_ptr().add(y * self.stride), self.width)
+    }
+
+    /// # Safety
+    /// The view of these cells needs to be borrowed for `'a`.
+    unsafe fn rows<'a>(self) -> impl Iterator<Item = &'a [T]>
+    where
+        T: 'a,
+    {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// # Safety
+    /// The mutable view of these cells needs to be borrowed mutably for `'a`.
+    unsafe fn rows_mut<'a>(self) -> impl Iterator<Item = &'a mut [T]>
+    where
+        T: 'a,
+    {
+        (0..self.height).map(move |y| self.row_mut(y))
+    }
+}
+
+/// A shared view of the cells of a grid in a box.
+///
+/// # Examples
+/// ```
+/// # use euclid::{Box2D, Point2D};
+/// # use euclid_ext::Grid2D;
+/// enum Space {}
+/// let bounds = Box2D::new(Point2D::<i32, Space>::new(-2, -2), Point2D::new(2, 2));
+/// let grid = Grid2D::from_fn(bounds, |p| p.x + p.y * 10);
+/// let view = grid.view(Box2D::new(Point2D::new(0, -1), Point2D::new(2, 1)));
+/// assert_eq!(4, view.len());
+/// assert_eq!(-9, view[Point2D::new(1, -1)]);
+/// assert_eq!(Some(&-9), view.get_local(Point2D::new(1, 0)));
+/// assert_eq!(None, view.get(Point2D::new(-1, 0)));
+/// assert_eq!(vec![-10, -9, 0, 1], view.iter().map(|(_, &c)| c).collect::<Vec<_>>());
+/// ```
+pub struct GridView2D<'a, T, U> {
+    bounds: Box2D<i32, U>,
+    cells: Cells<T>,
+    marker: PhantomData<&'a [T]>,
+}
+
+impl<'a, T, U> GridView2D<'a, T, U> {
+    /// Makes a view of `view` in a grid over `bounds` storing `cells` in the order of `Points`.
+    ///
+    /// # Panics
+    /// Panics if `view` is not empty and not contained in `bounds`.
+    pub(crate) fn new(bounds: Box2D<i32, U>, cells: &'a [T], view: Box2D<i32, U>) -> Self {
+        let width = extent(bounds.min.x, bounds.max.x);
+        Self {
+            bounds,
+            cells: Cells::new(NonNull::from(cells).cast(), cells.len(), width),
+            marker: PhantomData,
+        }
+        .view(view)
+    }
+
+    pub fn bounds(&self) -> Box2D<i32, U> {
+        self.bounds
+    }
+
+    fn size(&self) -> (usize, usize) {
+        self.cells.size()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn contains(&self, point: Point2D<i32, U>) -> bool {
+        self.bounds.contains(point)
+    }
+
+    /// Returns the point of the grid at `local` relative to the minimum of the bounds.
+    pub fn to_global(&self, local: Point2D<i32, U>) -> Point2D<i32, U> {
+        self.bounds.min + local.to_vector()
+    }
+
+    /// Returns `point` of the grid relative to the minimum of the bounds.
+    pub fn to_local(&self, point: Point2D<i32, U>) -> Point2D<i32, U> {
+        point - self.bounds.min.to_vector()
+    }
+
+    /// Returns the cell at `point` of the grid, or `None` if it is out of the bounds.
+    pub fn get(&self, point: Point2D<i32, U>) -> Option<&'a T> {
+        self.cell(offset_in(&self.bounds, point)?)
+    }
+
+    /// Returns the cell at `local` relative to the minimum of the bounds, or `None` if it is out
+    /// of them.
+    pub fn get_local(&self, local: Point2D<i32, U>) -> Option<&'a T> {
+        self.cell(local_offset(self.size(), local)?)
+    }
+
+    fn cell(&self, offset: (usize, usize)) -> Option<&'a T> {
+        let index = self.cells.index(offset)?;
+        // The cells are borrowed for `'a`.
+        Some(unsafe { &*self.cells.first.as_ptr().add(index) })
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        // The cells are borrowed for `'a`.
+        unsafe { self.cells.rows() }
+    }
+
+    /// Iterates the points of the cells in the order of `Points` over the bounds.
+    pub fn points(&self) -> PointRangeIterator<Point2D<i32, U>> {
+        self.bounds.points()
+    }
+
+    /// Iterates the cells with their points in the order of `points`.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2D<i32, U>, &'a T)> {
+        self.points().zip(self.rows().flatten())
+    }
+
+    /// Makes a view of the cells of this view in `bounds`.
+    ///
+    /// # Panics
+    /// Panics if `bounds` is not empty and not contained in the bounds of this view.
+    pub fn view(&self, bounds: Box2D<i32, U>) -> GridView2D<'a, T, U> {
+        let (offset, size) = sub_view(&self.bounds, &bounds);
+        GridView2D {
+            bounds,
+            cells: self.cells.sub(offset, size),
+            marker: PhantomData,
+        }
+    }
+
+    /// Copies the cells into a grid over the bounds.
+    pub fn to_grid(&self) -> Grid2D<T, U>
+    where
+        T: Clone,
+    {
+        Grid2D::from_vec(self.bounds, self.rows().flatten().cloned().collect())
+    }
+}
+
+impl<'a, T, U> ops::Index<Point2D<i32, U>> for GridView2D<'a, T, U> {
+    type Output = T;
+
+    /// # Panics
+    /// Panics if `point` is out of the bounds.
+    fn index(&self, point: Point2D<i32, U>) -> &T {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!("point {:?} is out of the view bounds", point.to_array()),
+        }
+    }
+}
+
+// Implemented by hand so that they are not bounded on the cells or the unit.
+impl<'a, T, U> Clone for GridView2D<'a, T, U> {
+    fn clone(&self) -> Self {
+        Self {
+            bounds: self.bounds,
+            cells: self.cells,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, U> fmt::Debug for GridView2D<'a, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GridView2D")
+            .field("bounds", &self.bounds)
+            .field("rows", &self.rows().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// A mutable view of the cells of a grid in a box, which can be split into disjoint views.
+///
+/// # Examples
+/// ```
+/// # use euclid::{Box2D, Point2D};
+/// # use euclid_ext::Grid2D;
+/// enum Space {}
+/// let bounds = Box2D::new(Point2D::<i32, Space>::new(0, 0), Point2D::new(4, 2));
+/// let mut grid = Grid2D::new(bounds, '.');
+/// let (mut left, mut right) = grid.as_view_mut().split_at_x(1);
+/// left[Point2D::new(0, 1)] = '<';
+/// *right.get_local_mut(Point2D::new(2, 0)).unwrap() = '>';
+/// right.view_mut(Box2D::new(Point2D::new(1, 1), Point2D::new(3, 2))).fill('_');
+/// assert_eq!(&['.', '.', '.', '>', '<', '_', '_', '.'], grid.as_slice());
+/// ```
+pub struct GridViewMut2D<'a, T, U> {
+    bounds: Box2D<i32, U>,
+    cells: Cells<T>,
+    marker: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T, U> GridViewMut2D<'a, T, U> {
+    /// Makes a view of `view` in a grid over `bounds` storing `cells` in the order of `Points`.
+    ///
+    /// # Panics
+    /// Panics if `view` is not empty and not contained in `bounds`.
+    pub(crate) fn new(bounds: Box2D<i32, U>, cells: &'a mut [T], view: Box2D<i32, U>) -> Self {
+        let width = extent(bounds.min.x, bounds.max.x);
+        let len = cells.len();
+        Self {
+            bounds,
+            cells: Cells::new(NonNull::from(cells).cast(), len, width),
+            marker: PhantomData,
+        }
+        .into_view_mut(view)
+    }
+
+    pub fn bounds(&self) -> Box2D<i32, U> {
+        self.bounds
+    }
+
+    fn size(&self) -> (usize, usize) {
+        self.cells.size()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn contains(&self, point: Point2D<i32, U>) -> bool {
+        self.bounds.contains(point)
+    }
+
+    /// Returns the point of the grid at `local` relative to the minimum of the bounds.
+    pub fn to_global(&self, local: Point2D<i32, U>) -> Point2D<i32, U> {
+        self.bounds.min + local.to_vector()
+    }
+
+    /// Returns `point` of the grid relative to the minimum of the bounds.
+    pub fn to_local(&self, point: Point2D<i32, U>) -> Point2D<i32, U> {
+        point - self.bounds.min.to_vector()
+    }
+
+    /// Returns the cell at `point` of the grid, or `None` if it is out of the bounds.
+    pub fn get(&self, point: Point2D<i32, U>) -> Option<&T> {
+        self.cell(offset_in(&self.bounds, point)?)
+    }
+
+    /// Returns the cell at `point` of the grid, or `None` if it is out of the bounds.
+    pub fn get_mut(&mut self, point: Point2D<i32, U>) -> Option<&mut T> {
+        self.cell_mut(offset_in(&self.bounds, point)?)
+    }
+
+    /// Returns the cell at `local` relative to the minimum of the bounds, or `None` if it is out
+    /// of them.
+    pub fn get_local(&self, local: Point2D<i32, U>) -> Option<&T> {
+        self.cell(local_offset(self.size(), local)?)
+    }
+
+    /// Returns the cell at `local` relative to the minimum of the bounds, or `None` if it is out
+    /// of them.
+    pub fn get_local_mut(&mut self, local: Point2D<i32, U>) -> Option<&mut T> {
+        self.cell_mut(local_offset(self.size(), local)?)
+    }
+
+    fn cell(&self, offset: (usize, usize)) -> Option<&T> {
+        let index = self.cells.index(offset)?;
+        // The cells are borrowed for as long as `self` is.
+        Some(unsafe { &*self.cells.first.as_ptr().add(index) })
+    }
+
+    fn cell_mut(&mut self, offset: (usize, usize)) -> Option<&mut T> {
+        let index = self.cells.index(offset)?;
+        // The cells are borrowed mutably for as long as `self` is.
+        Some(unsafe { &mut *self.cells.first.as_ptr().add(index) })
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // The cells are borrowed for as long as `self` is.
+        unsafe { self.cells.rows() }
+    }
+
+    fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        // The cells are borrowed mutably for as long as `self` is.
+        unsafe { self.cells.rows_mut() }
+    }
+
+    /// Iterates the points of the cells in the order of `Points` over the bounds.
+    pub fn points(&self) -> PointRangeIterator<Point2D<i32, U>> {
+        self.bounds.points()
+    }
+
+    /// Iterates the cells with their points in the order of `points`.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2D<i32, U>, &T)> {
+        self.points().zip(self.rows().flatten())
+    }
+
+    /// Iterates the cells mutably with their points in the order of `points`.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point2D<i32, U>, &mut T)> {
+        let points = self.points();
+        points.zip(self.rows_mut().flatten())
+    }
+
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for row in self.rows_mut() {
+            row.fill(value.clone());
+        }
+    }
+
+    pub fn as_view(&self) -> GridView2D<'_, T, U> {
+        GridView2D {
+            bounds: self.bounds,
+            cells: self.cells,
+            marker: PhantomData,
+        }
+    }
+
+    /// Makes a mutable view of the cells of this view in `bounds`.
+    ///
+    /// # Panics
+    /// Panics if `bounds` is not empty and not contained in the bounds of this view.
+    pub fn view_mut(&mut self, bounds: Box2D<i32, U>) -> GridViewMut2D<'_, T, U> {
+        GridViewMut2D {
+            bounds: self.bounds,
+            cells: self.cells,
+            marker: PhantomData,
+        }
+        .into_view_mut(bounds)
+    }
+
+    /// Turns this view into a view of its cells in `bounds`.
+    ///
+    /// # Panics
+    /// Panics if `bounds` is not empty and not contained in the bounds of this view.
+    pub fn into_view_mut(self, bounds: Box2D<i32, U>) -> Self {
+        let (offset, size) = sub_view(&self.bounds, &bounds);
+        Self {
+            bounds,
+            cells: self.cells.sub(offset, size),
+            marker: PhantomData,
+        }
+    }
+
+    /// Splits this view into the views of its cells left of `x` and from `x` on.
+    ///
+    /// # Panics
+    /// Panics if `x` is not within the bounds along the x axis.
+    pub fn split_at_x(self, x: i32) -> (Self, Self) {
+        let (min, max) = (self.bounds.min, self.bounds.max);
+        assert!(
+            min.x <= x && x <= max.x,
+            "split {} is out of the view bounds",
+            x
+        );
+        let ((width, height), at) = (self.size(), extent(min.x, x).min(self.size().0));
+        (
+            Self {
+                bounds: Box2D::new(min, Point2D::new(x, max.y)),
+                cells: self.cells.sub((0, 0), (at, height)),
+                marker: PhantomData,
+            },
+            Self {
+                bounds: Box2D::new(Point2D::new(x, min.y), max),
+                cells: self.cells.sub((at, 0), (width - at, height)),
+                marker: PhantomData,
+            },
+        )
+    }
+
+    /// Splits this view into the views of its cells above `y` and from `y` on.
+    ///
+    /// # Panics
+    /// Panics if `y` is not within the bounds along the y axis.
+    pub fn split_at_y(self, y: i32) -> (Self, Self) {
+        let (min, max) = (self.bounds.min, self.bounds.max);
+        assert!(
+            min.y <= y && y <= max.y,
+            "split {} is out of the view bounds",
+            y
+        );
+        let ((width, height), at) = (self.size(), extent(min.y, y).min(self.size().1));
+        (
+            Self {
+                bounds: Box2D::new(min, Point2D::new(max.x, y)),
+                cells: self.cells.sub((0, 0), (width, at)),
+                marker: PhantomData,
+            },
+            Self {
+                bounds: Box2D::new(Point2D::new(min.x, y), max),
+                cells: self.cells.sub((0, at), (width, height - at)),
+                marker: PhantomData,
+            },
+        )
+    }
+
+    /// Copies the cells of `source` into the cells at the same local points.
+    ///
+    /// # Panics
+    /// Panics if `source` does not have the same size.
+    pub fn copy_from(&mut self, source: &GridView2D<T, U>)
+    where
+        T: Clone,
+    {
+        assert_eq!(self.size(), source.size(), "views need the same size");
+        for (row, source) in self.rows_mut().zip(source.rows()) {
+            row.clone_from_slice(source);
+        }
+    }
+
+    /// Copies the cells of `source` into the cells at the same points relative to `origin`,
+    /// skipping those out of the bounds.
+    pub fn blit(&mut self, source: &GridView2D<T, U>, origin: Point2D<i32, U>)
+    where
+        T: Clone,
+    {
+        let (width, height) = source.size();
+        let (width, height) = (width as i64, height as i64);
+        let (min, max) = (self.bounds.min, self.bounds.max);
+        let origin = (i64::from(origin.x), i64::from(origin.y));
+        let start = (origin.0.max(min.x.into()), origin.1.max(min.y.into()));
+        let end = (
+            (origin.0 + width).min(max.x.into()),
+            (origin.1 + height).min(max.y.into()),
+        );
+        if start.0 >= end.0 {
+            return;
+        }
+        let columns = (
+            (start.0 - i64::from(min.x)) as usize,
+            (end.0 - i64::from(min.x)) as usize,
+        );
+        let source_columns = ((start.0 - origin.0) as usize, (end.0 - origin.0) as usize);
+        let rows = self.rows_mut().skip((start.1 - i64::from(min.y)) as usize);
+        let source_rows = source.rows().skip((start.1 - origin.1) as usize);
+        for (row, source) in rows
+            .zip(source_rows)
+            .take((end.1 - start.1).max(0) as usize)
+        {
+            row[columns.0..columns.1].clone_from_slice(&source[source_columns.0..source_columns.1]);
+        }
+    }
+}
+
+impl<'a, T, U> ops::Index<Point2D<i32, U>> for GridViewMut2D<'a, T, U> {
+    type Output = T;
+
+    /// # Panics
+    /// Panics if `point` is out of the bounds.
+    fn index(&self, point: Point2D<i32, U>) -> &T {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!("point {:?} is out of the view bounds", point.to_array()),
+        }
+    }
+}
+
+impl<'a, T, U> ops::IndexMut<Point2D<i32, U>> for GridViewMut2D<'a, T, U> {
+    /// # Panics
+    /// Panics if `point` is out of the bounds.
+    fn index_mut(&mut self, point: Point2D<i32, U>) -> &mut T {
+        match self.get_mut(point) {
+            Some(cell) => cell,
+            None => panic!("point {:?} is out of the view bounds", point.to_array()),
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, U> fmt::Debug for GridViewMut2D<'a, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GridViewMut2D")
+            .field("bounds", &self.bounds)
+            .field("rows", &self.rows().collect::<Vec<_>>())
+            .finish()
+    }
+}
@@ -1,10 +1,62 @@
+pub mod cell_coverage;
+pub mod curve;
+pub mod curve_iterator;
+pub mod curve_points;
+pub mod flood_fill;
+pub mod flood_fill_iterator;
+pub mod grid;
+pub mod grid_layout;
+pub mod grid_transform;
+pub mod grid_view;
+pub mod line_iterator;
+pub mod line_points;
 pub mod map;
+pub mod neighborhood;
+pub mod neighborhood_iterator;
+pub mod neighbors;
+pub mod order;
+#[cfg(feature = "rayon")]
+pub mod par_point_range_iterator;
+#[cfg(feature = "rayon")]
+pub mod par_points;
 pub mod point_range_iterator;
 pub mod points;
+pub mod ray_iterator;
+pub mod ray_voxels;
+pub mod shape;
+pub mod shape_iterator;
+pub mod shape_points;
+pub mod shell_iterator;
+pub mod shell_points;
+pub mod sparse_grid;
+pub mod spiral_iterator;
+pub mod spiral_points;
 pub mod structure;
+pub mod tile_iterator;
+pub mod tiles;
 pub mod to_point_range;
 
+pub use crate::cell_coverage::CellCoverage;
+pub use crate::curve_points::CurvePoints;
+pub use crate::flood_fill::FloodFill;
+pub use crate::grid::{Grid2D, Grid3D};
+pub use crate::grid_layout::{GridLayout2D, GridLayout3D};
+pub use crate::grid_view::{GridView2D, GridViewMut2D};
+pub use crate::line_iterator::LineMode;
+pub use crate::line_points::LinePoints;
 pub use crate::map::{Map2D, Map3D};
+pub use crate::neighborhood::{Neighborhood2D, Neighborhood3D};
+pub use crate::neighbors::Neighbors;
+pub use crate::order::{Order2D, Order3D};
+#[cfg(feature = "rayon")]
+pub use crate::par_points::ParPoints;
 pub use crate::points::Points;
+pub use crate::ray_voxels::RayVoxels;
+pub use crate::shape::{Ball, Circle, Disk, Ellipse, FillRule, Polygon, Shape, Triangle};
+pub use crate::shape_points::ShapePoints;
+pub use crate::shell_points::ShellPoints;
+pub use crate::sparse_grid::{SparseGrid2D, SparseGrid3D};
+pub use crate::spiral_points::SpiralPoints;
 pub use crate::structure::Structure2D;
-pub use crate::to_point_range::ToPointRange;
+pub use crate::tiles::Tiles;
+pub use crate::to_point_range::{IntoPointRange, ToPointRange};
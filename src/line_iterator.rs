@@ -0,0 +1,213 @@
+use std::convert::TryFrom;
+use std::iter;
+
+const TOO_MANY_POINTS: &str = "line has more than usize::MAX points";
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Ways of choosing the cells on a segment between the centers of two cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LineMode {
+    /// The cell nearest to the segment at each step along the axis it spans further,
+    /// rounding ties towards the larger coordinate seen from the lexicographically smaller end.
+    #[default]
+    Bresenham,
+    /// Every cell the segment touches, including both cells beside a corner it passes through.
+    Supercover,
+}
+
+/// Iterates the cells on a segment from its start to its end.
+///
+/// The cells from `b` to `a` are always the cells from `a` to `b` in reverse.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LineIterator<T> {
+    start: T,
+    end: T,
+    mode: LineMode,
+    next: [i64; 2],
+    index: i64,
+    cell: [i64; 2],
+    crossings: [i64; 2],
+    corner: u8,
+    remaining: usize,
+}
+
+impl<U> LineIterator<euclid::Point2D<i32, U>> {
+    /// # Examples
+    /// ```
+    /// # use euclid::Point2D;
+    /// # use euclid_ext::LineMode;
+    /// # use euclid_ext::line_iterator::LineIterator;
+    /// enum Space {}
+    /// let mut i = LineIterator::new(Point2D::<i32, Space>::new(0, 0), Point2D::new(3, 1), LineMode::Bresenham);
+    /// assert_eq!(Some(Point2D::new(0, 0)), i.next());
+    /// assert_eq!(Some(Point2D::new(1, 0)), i.next());
+    /// assert_eq!(Some(Point2D::new(2, 1)), i.next());
+    /// assert_eq!(Some(Point2D::new(3, 1)), i.next());
+    /// assert_eq!(None, i.next());
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the line has more than `usize::MAX` points.
+    pub fn new(
+        start: euclid::Point2D<i32, U>,
+        end: euclid::Point2D<i32, U>,
+        mode: LineMode,
+    ) -> Self {
+        let delta = [
+            (i64::from(end.x) - i64::from(start.x)).abs(),
+            (i64::from(end.y) - i64::from(start.y)).abs(),
+        ];
+        let len = match mode {
+            LineMode::Bresenham => delta[0].max(delta[1]) + 1,
+            LineMode::Supercover => {
+                // The segment passes through a corner once per step of the reduced delta when
+                // both of its components are odd.
+                let g = gcd(delta[0], delta[1]);
+                let corners = if g > 0 && (delta[0] / g) % 2 == 1 && (delta[1] / g) % 2 == 1 {
+                    g
+                } else {
+                    0
+                };
+                delta[0] + delta[1] + 1 + corners
+            }
+        };
+        let start_array = [i64::from(start.x), i64::from(start.y)];
+        Self {
+            start,
+            end,
+            mode,
+            next: start_array,
+            index: 0,
+            cell: start_array,
+            crossings: [0, 0],
+            corner: 0,
+            remaining: usize::try_from(len).expect(TOO_MANY_POINTS),
+        }
+    }
+
+    fn signs(&self) -> [i64; 2] {
+        [
+            (i64::from(self.end.x) - i64::from(self.start.x)).signum(),
+            (i64::from(self.end.y) - i64::from(self.start.y)).signum(),
+        ]
+    }
+
+    fn deltas(&self) -> [i64; 2] {
+        [
+            (i64::from(self.end.x) - i64::from(self.start.x)).abs(),
+            (i64::from(self.end.y) - i64::from(self.start.y)).abs(),
+        ]
+    }
+
+    // The points are computed from the lexicographically smaller end, so that swapping the ends
+    // does not change how ties are rounded.
+    fn bresenham(&self, index: i64) -> [i64; 2] {
+        let (mut from, mut to) = (self.start, self.end);
+        let deltas = self.deltas();
+        let steps = deltas[0].max(deltas[1]);
+        let index = if (from.x, from.y) <= (to.x, to.y) {
+            index
+        } else {
+            std::mem::swap(&mut from, &mut to);
+            steps - index
+        };
+        let from = [i64::from(from.x), i64::from(from.y)];
+        let to = [i64::from(to.x), i64::from(to.y)];
+        let major = if deltas[0] >= deltas[1] { 0 } else { 1 };
+        let mut point = from;
+        point[major] += (to[major] - from[major]).signum() * index;
+        let minor = 1 - major;
+        let numerator =
+            2 * i128::from(index) * i128::from(to[minor] - from[minor]) + i128::from(steps);
+        point[minor] += numerator.div_euclid(2 * i128::from(steps)) as i64;
+        point
+    }
+
+    // Cells are entered in the order their boundaries are crossed. Crossing a corner enters the
+    // cells beside it first, the one across the x boundary before the one across the y boundary.
+    fn supercover(&mut self) -> [i64; 2] {
+        let (signs, deltas) = (self.signs(), self.deltas());
+        match self.corner {
+            1 => {
+                self.corner = 2;
+                return [self.cell[0], self.cell[1] + signs[1]];
+            }
+            2 => {
+                self.corner = 0;
+                self.crossings = [self.crossings[0] + 1, self.crossings[1] + 1];
+                self.cell = [self.cell[0] + signs[0], self.cell[1] + signs[1]];
+                return self.cell;
+            }
+            _ => {}
+        }
+        // The crossing of boundary `k` along an axis is at `(2k + 1) / (2 * delta)` along the
+        // segment, so crossings are compared by cross-multiplying.
+        let at = |axis: usize, other: usize| {
+            if self.crossings[axis] < deltas[axis] {
+                Some((2 * i128::from(self.crossings[axis]) + 1) * i128::from(deltas[other]))
+            } else {
+                None
+            }
+        };
+        let (x, y) = (at(0, 1), at(1, 0));
+        let axis = match (x, y) {
+            (Some(x), Some(y)) if x == y => {
+                self.corner = 1;
+                return [self.cell[0] + signs[0], self.cell[1]];
+            }
+            (Some(x), Some(y)) => (y < x) as usize,
+            (Some(_), None) => 0,
+            _ => 1,
+        };
+        self.crossings[axis] += 1;
+        self.cell[axis] += signs[axis];
+        self.cell
+    }
+}
+
+impl<U> Iterator for LineIterator<euclid::Point2D<i32, U>> {
+    type Item = euclid::Point2D<i32, U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let point = self.next;
+        if self.remaining > 0 {
+            self.index += 1;
+            self.next = match self.mode {
+                LineMode::Bresenham => self.bresenham(self.index),
+                LineMode::Supercover => self.supercover(),
+            };
+        }
+        Some(euclid::Point2D::new(point[0] as i32, point[1] as i32))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn count(self) -> usize {
+        self.remaining
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        if self.remaining > 0 {
+            Some(self.end)
+        } else {
+            None
+        }
+    }
+}
+
+impl<U> ExactSizeIterator for LineIterator<euclid::Point2D<i32, U>> {}
+
+impl<U> iter::FusedIterator for LineIterator<euclid::Point2D<i32, U>> {}
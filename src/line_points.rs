@@ -0,0 +1,26 @@
+use crate::line_iterator::{LineIterator, LineMode};
+
+/// # Examples
+/// ```
+/// # use euclid::Point2D;
+/// # use euclid_ext::{LineMode, LinePoints};
+/// enum Space {}
+/// let start = Point2D::<i32, Space>::new(0, 0);
+/// assert_eq!(
+///     vec![Point2D::new(0, 0), Point2D::new(1, 0), Point2D::new(0, 1), Point2D::new(1, 1)],
+///     start.line_points(Point2D::new(1, 1), LineMode::Supercover).collect::<Vec<_>>());
+/// ```
+pub trait LinePoints {
+    type Iter: Iterator;
+
+    /// Iterates the cells on the segment from `self` to `end`, including both ends.
+    fn line_points(self, end: Self, mode: LineMode) -> Self::Iter;
+}
+
+impl<U> LinePoints for euclid::Point2D<i32, U> {
+    type Iter = LineIterator<Self>;
+
+    fn line_points(self, end: Self, mode: LineMode) -> Self::Iter {
+        LineIterator::new(self, end, mode)
+    }
+}
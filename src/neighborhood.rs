@@ -0,0 +1,43 @@
+/// Neighborhoods of 2D points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Neighborhood2D {
+    /// The von Neumann neighborhood, the points within a Manhattan distance of the radius.
+    N4,
+    /// The Moore neighborhood, the points within a Chebyshev distance of the radius.
+    N8,
+}
+
+impl Neighborhood2D {
+    pub(crate) fn contains(self, offset: [i128; 2], radius: i128) -> bool {
+        let [x, y] = offset;
+        match self {
+            Self::N4 => x.abs() + y.abs() <= radius,
+            Self::N8 => x.abs().max(y.abs()) <= radius,
+        }
+    }
+}
+
+/// Neighborhoods of 3D points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Neighborhood3D {
+    /// The von Neumann neighborhood, the points within a Manhattan distance of the radius.
+    N6,
+    /// The points within a Chebyshev distance of the radius and a Manhattan distance of twice
+    /// the radius, which are the points sharing a face or an edge when the radius is one.
+    N18,
+    /// The Moore neighborhood, the points within a Chebyshev distance of the radius.
+    N26,
+}
+
+impl Neighborhood3D {
+    pub(crate) fn contains(self, offset: [i128; 3], radius: i128) -> bool {
+        let [x, y, z] = offset;
+        let chebyshev = x.abs().max(y.abs()).max(z.abs());
+        let manhattan = x.abs() + y.abs() + z.abs();
+        match self {
+            Self::N6 => manhattan <= radius,
+            Self::N18 => chebyshev <= radius && manhattan <= radius * 2,
+            Self::N26 => chebyshev <= radius,
+        }
+    }
+}
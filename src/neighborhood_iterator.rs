@@ -0,0 +1,228 @@
+use crate::neighborhood::{Neighborhood2D, Neighborhood3D};
+use crate::point_range_iterator::{
+    offset_bounds, PointRange, PointRangeIterator, PointRangeIteratorItem,
+    PointRangeIteratorPrimitive,
+};
+use crate::to_point_range::IntoPointRange;
+use std::{fmt, hash, iter, ops};
+
+pub trait NeighborhoodIteratorItem: PointRangeIteratorItem {
+    type Neighborhood: Copy + fmt::Debug + Eq + hash::Hash;
+
+    type Offset;
+
+    /// Offsets from a point, represented as points so that they can be iterated as a range.
+    type Offsets: PointRangeIteratorItem;
+
+    /// Returns the offsets up to `radius` along every axis which keep `self` in `bounds`.
+    fn offsets(
+        self,
+        radius: i128,
+        bounds: Option<&PointRange<Self>>,
+    ) -> ops::RangeInclusive<Self::Offsets>;
+
+    /// Returns the neighbor at `offset` and the offset itself, or `None` if `offset` is outside
+    /// `neighborhood` or either is not representable.
+    fn neighbor(
+        self,
+        neighborhood: Self::Neighborhood,
+        radius: i128,
+        offset: Self::Offsets,
+    ) -> Option<(Self, Self::Offset)>;
+}
+
+// Offsets are yielded as vectors of the coordinate type, which is therefore required to be signed.
+macro_rules! impl_neighborhood_iterator_item {
+    ($point:ident, $vector:ident, $neighborhood:ident, $dimensions:literal) => {
+        impl<T, U> NeighborhoodIteratorItem for euclid::$point<T, U>
+        where
+            T: PointRangeIteratorPrimitive + ops::Neg<Output = T> + euclid::num::Zero,
+        {
+            type Neighborhood = $neighborhood;
+
+            type Offset = euclid::$vector<T, U>;
+
+            type Offsets = euclid::$point<i128, U>;
+
+            fn offsets(
+                self,
+                radius: i128,
+                bounds: Option<&PointRange<Self>>,
+            ) -> ops::RangeInclusive<Self::Offsets> {
+                let (mut min, mut max) = ([-radius; $dimensions], [radius; $dimensions]);
+                if let Some(bounds) = bounds {
+                    let center = self.to_array();
+                    let (start, end) = (bounds.start.to_array(), bounds.end.to_array());
+                    for axis in 0..$dimensions {
+                        let (first, last) = offset_bounds(
+                            center[axis],
+                            start[axis],
+                            end[axis],
+                            bounds.end_inclusive,
+                        );
+                        min[axis] = min[axis].max(first);
+                        max[axis] = max[axis].min(last);
+                    }
+                }
+                Self::Offsets::from(min)..=Self::Offsets::from(max)
+            }
+
+            fn neighbor(
+                self,
+                neighborhood: Self::Neighborhood,
+                radius: i128,
+                offset: Self::Offsets,
+            ) -> Option<(Self, Self::Offset)> {
+                let offset = offset.to_array();
+                if offset == [0; $dimensions] || !neighborhood.contains(offset, radius) {
+                    return None;
+                }
+                let mut point = self.to_array();
+                let mut vector = [T::zero(); $dimensions];
+                for axis in 0..$dimensions {
+                    point[axis] = point[axis].checked_offset(offset[axis])?;
+                    vector[axis] = T::zero().checked_offset(offset[axis])?;
+                }
+                Some((point.into(), vector.into()))
+            }
+        }
+    };
+}
+
+impl_neighborhood_iterator_item!(Point2D, Vector2D, Neighborhood2D, 2);
+
+impl_neighborhood_iterator_item!(Point3D, Vector3D, Neighborhood3D, 3);
+
+/// Iterates the neighbors of a point in row-major order, together with their offsets from it.
+#[derive(Clone)]
+pub struct NeighborhoodIterator<T: NeighborhoodIteratorItem> {
+    center: T,
+    neighborhood: T::Neighborhood,
+    radius: i128,
+    offsets: PointRangeIterator<T::Offsets>,
+}
+
+impl<T: NeighborhoodIteratorItem + fmt::Debug> fmt::Debug for NeighborhoodIterator<T>
+where
+    T::Neighborhood: fmt::Debug,
+    PointRangeIterator<T::Offsets>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NeighborhoodIterator")
+            .field("center", &self.center)
+            .field("neighborhood", &self.neighborhood)
+            .field("radius", &self.radius)
+            .field("offsets", &self.offsets)
+            .finish()
+    }
+}
+
+impl<T: NeighborhoodIteratorItem + PartialEq> PartialEq for NeighborhoodIterator<T>
+where
+    T::Neighborhood: PartialEq,
+    PointRangeIterator<T::Offsets>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.center == other.center
+            && self.neighborhood == other.neighborhood
+            && self.radius == other.radius
+            && self.offsets == other.offsets
+    }
+}
+
+impl<T: NeighborhoodIteratorItem> NeighborhoodIterator<T> {
+    /// # Examples
+    /// ```
+    /// # use euclid::{Point2D, Vector2D};
+    /// # use euclid_ext::Neighborhood2D;
+    /// # use euclid_ext::neighborhood_iterator::NeighborhoodIterator;
+    /// enum Space {}
+    /// let mut i = NeighborhoodIterator::new(Point2D::<i64, Space>::new(10, 20), Neighborhood2D::N4, 1);
+    /// assert_eq!(Some((Point2D::new(10, 19), Vector2D::new(0, -1))), i.next());
+    /// assert_eq!(Some((Point2D::new(9, 20), Vector2D::new(-1, 0))), i.next());
+    /// assert_eq!(Some((Point2D::new(11, 20), Vector2D::new(1, 0))), i.next());
+    /// assert_eq!(Some((Point2D::new(10, 21), Vector2D::new(0, 1))), i.next());
+    /// assert_eq!(None, i.next());
+    /// ```
+    pub fn new(center: T, neighborhood: T::Neighborhood, radius: usize) -> Self {
+        Self::from_bounds(center, None, neighborhood, radius)
+    }
+
+    /// Makes an iterator of the neighbors of `center` within `bounds`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use euclid::{Point2D, Vector2D};
+    /// # use euclid_ext::Neighborhood2D;
+    /// # use euclid_ext::neighborhood_iterator::NeighborhoodIterator;
+    /// enum Space {}
+    /// let bounds = Point2D::<i64, Space>::new(0, 0)..Point2D::<i64, Space>::new(5, 5);
+    /// let mut i = NeighborhoodIterator::with_bounds(Point2D::new(0, 4), bounds, Neighborhood2D::N8, 1);
+    /// assert_eq!(Some((Point2D::new(0, 3), Vector2D::new(0, -1))), i.next());
+    /// assert_eq!(Some((Point2D::new(1, 3), Vector2D::new(1, -1))), i.next());
+    /// assert_eq!(Some((Point2D::new(1, 4), Vector2D::new(1, 0))), i.next());
+    /// assert_eq!(None, i.next());
+    /// ```
+    pub fn with_bounds<B: IntoPointRange<Point = T>>(
+        center: T,
+        bounds: B,
+        neighborhood: T::Neighborhood,
+        radius: usize,
+    ) -> Self {
+        Self::from_bounds(
+            center,
+            Some(&bounds.into_point_range()),
+            neighborhood,
+            radius,
+        )
+    }
+
+    fn from_bounds(
+        center: T,
+        bounds: Option<&PointRange<T>>,
+        neighborhood: T::Neighborhood,
+        radius: usize,
+    ) -> Self {
+        let radius = radius as i128;
+        Self {
+            center,
+            neighborhood,
+            radius,
+            offsets: PointRangeIterator::inclusive(center.offsets(radius, bounds)),
+        }
+    }
+
+    fn neighbor(&self, offset: T::Offsets) -> Option<(T, T::Offset)> {
+        self.center.neighbor(self.neighborhood, self.radius, offset)
+    }
+}
+
+impl<T: NeighborhoodIteratorItem> Iterator for NeighborhoodIterator<T> {
+    type Item = (T, T::Offset);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let offset = self.offsets.next()?;
+            if let Some(neighbor) = self.neighbor(offset) {
+                return Some(neighbor);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.offsets.len()))
+    }
+}
+
+impl<T: NeighborhoodIteratorItem> DoubleEndedIterator for NeighborhoodIterator<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            let offset = self.offsets.next_back()?;
+            if let Some(neighbor) = self.neighbor(offset) {
+                return Some(neighbor);
+            }
+        }
+    }
+}
+
+impl<T: NeighborhoodIteratorItem> iter::FusedIterator for NeighborhoodIterator<T> {}
@@ -0,0 +1,51 @@
+use crate::neighborhood_iterator::{NeighborhoodIterator, NeighborhoodIteratorItem};
+use crate::to_point_range::IntoPointRange;
+
+/// # Examples
+/// ```
+/// # use euclid::{Box3D, Point3D};
+/// # use euclid_ext::{Neighborhood3D, Neighbors};
+/// enum Space {}
+/// let center = Point3D::<i64, Space>::new(0, 0, 0);
+/// assert_eq!(6, center.neighbors(Neighborhood3D::N6, 1).count());
+/// assert_eq!(18, center.neighbors(Neighborhood3D::N18, 1).count());
+/// assert_eq!(26, center.neighbors(Neighborhood3D::N26, 1).count());
+/// let bounds = Box3D::new(Point3D::new(0, 0, 0), Point3D::new(10, 10, 10));
+/// assert_eq!(7, center.neighbors_within(bounds, Neighborhood3D::N26, 1).count());
+/// ```
+pub trait Neighbors: Sized {
+    type Neighborhood;
+
+    type Iter: Iterator;
+
+    /// Iterates the points in `neighborhood` of `radius` around `self`, excluding `self`.
+    fn neighbors(self, neighborhood: Self::Neighborhood, radius: usize) -> Self::Iter;
+
+    /// Iterates the points in `neighborhood` of `radius` around `self` which are in `bounds`,
+    /// excluding `self`.
+    fn neighbors_within<B: IntoPointRange<Point = Self>>(
+        self,
+        bounds: B,
+        neighborhood: Self::Neighborhood,
+        radius: usize,
+    ) -> Self::Iter;
+}
+
+impl<T: NeighborhoodIteratorItem> Neighbors for T {
+    type Neighborhood = T::Neighborhood;
+
+    type Iter = NeighborhoodIterator<T>;
+
+    fn neighbors(self, neighborhood: Self::Neighborhood, radius: usize) -> Self::Iter {
+        NeighborhoodIterator::new(self, neighborhood, radius)
+    }
+
+    fn neighbors_within<B: IntoPointRange<Point = Self>>(
+        self,
+        bounds: B,
+        neighborhood: Self::Neighborhood,
+        radius: usize,
+    ) -> Self::Iter {
+        NeighborhoodIterator::with_bounds(self, bounds, neighborhood, radius)
+    }
+}
@@ -0,0 +1,65 @@
+/// Axis orders for iterating 2D points, naming the axes from the fastest-varying to the slowest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Order2D {
+    /// Row-major order.
+    #[default]
+    XY,
+    /// Column-major order.
+    YX,
+}
+
+impl Order2D {
+    pub(crate) fn axes(self) -> [usize; 2] {
+        match self {
+            Self::XY => [0, 1],
+            Self::YX => [1, 0],
+        }
+    }
+
+    /// Returns the order of `axes`, which must be a permutation of the axes.
+    pub(crate) fn from_axes(axes: [usize; 2]) -> Self {
+        match axes {
+            [0, 1] => Self::XY,
+            [1, 0] => Self::YX,
+            _ => unreachable!("{:?} is not a permutation of the axes", axes),
+        }
+    }
+}
+
+/// Axis orders for iterating 3D points, naming the axes from the fastest-varying to the slowest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Order3D {
+    #[default]
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl Order3D {
+    pub(crate) fn axes(self) -> [usize; 3] {
+        match self {
+            Self::XYZ => [0, 1, 2],
+            Self::XZY => [0, 2, 1],
+            Self::YXZ => [1, 0, 2],
+            Self::YZX => [1, 2, 0],
+            Self::ZXY => [2, 0, 1],
+            Self::ZYX => [2, 1, 0],
+        }
+    }
+
+    /// Returns the order of `axes`, which must be a permutation of the axes.
+    pub(crate) fn from_axes(axes: [usize; 3]) -> Self {
+        match axes {
+            [0, 1, 2] => Self::XYZ,
+            [0, 2, 1] => Self::XZY,
+            [1, 0, 2] => Self::YXZ,
+            [1, 2, 0] => Self::YZX,
+            [2, 0, 1] => Self::ZXY,
+            [2, 1, 0] => Self::ZYX,
+            _ => unreachable!("{:?} is not a permutation of the axes", axes),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::point_range_iterator::{PointRangeIterator, PointRangeIteratorItem};
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use std::fmt;
+
+/// A parallel version of `PointRangeIterator`, which can be split at any point in constant time.
+#[derive(Clone)]
+pub struct ParPointRangeIterator<T: PointRangeIteratorItem> {
+    iter: PointRangeIterator<T>,
+}
+
+impl<T: PointRangeIteratorItem> fmt::Debug for ParPointRangeIterator<T>
+where
+    PointRangeIterator<T>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ParPointRangeIterator")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<T: PointRangeIteratorItem + Send> IntoParallelIterator for PointRangeIterator<T>
+where
+    T::Step: Send,
+    T::Order: Send,
+{
+    type Iter = ParPointRangeIterator<T>;
+
+    type Item = T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParPointRangeIterator { iter: self }
+    }
+}
+
+impl<T: PointRangeIteratorItem + Send> ParallelIterator for ParPointRangeIterator<T>
+where
+    T::Step: Send,
+    T::Order: Send,
+{
+    type Item = T;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+impl<T: PointRangeIteratorItem + Send> IndexedParallelIterator for ParPointRangeIterator<T>
+where
+    T::Step: Send,
+    T::Order: Send,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(PointRangeProducer { iter: self.iter })
+    }
+}
+
+struct PointRangeProducer<T: PointRangeIteratorItem> {
+    iter: PointRangeIterator<T>,
+}
+
+impl<T: PointRangeIteratorItem + Send> Producer for PointRangeProducer<T>
+where
+    T::Step: Send,
+    T::Order: Send,
+{
+    type Item = T;
+
+    type IntoIter = PointRangeIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.iter.split_at(index);
+        (Self { iter: left }, Self { iter: right })
+    }
+}
@@ -0,0 +1,33 @@
+use crate::par_point_range_iterator::ParPointRangeIterator;
+use crate::point_range_iterator::{PointRangeIterator, PointRangeIteratorItem};
+use crate::points::Points;
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator};
+
+/// # Examples
+/// ```
+/// # use euclid::{Box2D, Point2D};
+/// # use euclid_ext::ParPoints;
+/// # use rayon::iter::ParallelIterator;
+/// enum Space {}
+/// let range = Box2D::new(Point2D::<i64, Space>::new(0, 0), Point2D::<i64, Space>::new(100, 100));
+/// assert_eq!(990000, range.par_points().map(|p| p.x + p.y).sum::<i64>());
+/// ```
+pub trait ParPoints {
+    type Iter: IndexedParallelIterator;
+
+    fn par_points(self) -> Self::Iter;
+}
+
+impl<T, P> ParPoints for T
+where
+    T: Points<Iter = PointRangeIterator<P>>,
+    P: PointRangeIteratorItem + Send,
+    P::Step: Send,
+    P::Order: Send,
+{
+    type Iter = ParPointRangeIterator<P>;
+
+    fn par_points(self) -> Self::Iter {
+        self.points().into_par_iter()
+    }
+}
@@ -1,61 +1,724 @@
-use std::ops;
+use crate::order::{Order2D, Order3D};
+use std::convert::TryFrom;
+use std::{fmt, hash, iter, ops};
 
+/// The coordinates of the points which `PointRangeIterator` can iterate.
+///
+/// It is implemented for the primitive integer and floating-point types. Other numeric types can
+/// be iterated by implementing it for them.
+///
+/// # Examples
+/// ```
+/// # use euclid::Point2D;
+/// # use euclid_ext::Points;
+/// # use euclid_ext::point_range_iterator::PointRangeIteratorPrimitive;
+/// # use std::ops;
+/// #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+/// struct Cell(i32);
+///
+/// impl ops::Add for Cell {
+///     type Output = Self;
+///
+///     fn add(self, other: Self) -> Self {
+///         Cell(self.0 + other.0)
+///     }
+/// }
+///
+/// impl euclid::num::One for Cell {
+///     fn one() -> Self {
+///         Cell(1)
+///     }
+/// }
+///
+/// impl PointRangeIteratorPrimitive for Cell {
+///     fn steps(start: Self, end: Self) -> usize {
+///         i32::steps(start.0, end.0)
+///     }
+///
+///     fn steps_by(start: Self, end: Self, step: Self) -> usize {
+///         i32::steps_by(start.0, end.0, step.0)
+///     }
+///
+///     fn inclusive_steps_by(start: Self, end: Self, step: Self) -> usize {
+///         i32::inclusive_steps_by(start.0, end.0, step.0)
+///     }
+///
+///     fn offset(self, n: usize) -> Self {
+///         Cell(self.0.offset(n))
+///     }
+///
+///     fn offset_by(self, n: usize, step: Self) -> Self {
+///         Cell(self.0.offset_by(n, step.0))
+///     }
+///
+///     fn checked_offset(self, n: i128) -> Option<Self> {
+///         self.0.checked_offset(n).map(Cell)
+///     }
+/// }
+///
+/// enum Space {}
+/// let range = Point2D::<_, Space>::new(Cell(0), Cell(0))..Point2D::new(Cell(2), Cell(1));
+/// assert_eq!(
+///     vec![Point2D::new(Cell(0), Cell(0)), Point2D::new(Cell(1), Cell(0))],
+///     range.points().collect::<Vec<_>>());
+/// ```
 pub trait PointRangeIteratorPrimitive:
     Copy + PartialOrd + ops::Add<Output = Self> + euclid::num::One
 {
-}
+    /// Returns how many of `start`, `start + 1`, `start + 2`, ... are less than `end`.
+    fn steps(start: Self, end: Self) -> usize;
 
-impl<T: Copy + PartialOrd + ops::Add<Output = Self> + euclid::num::One> PointRangeIteratorPrimitive
-    for T
-{
+    /// Returns how many of `start`, `start + step`, `start + step * 2`, ... are less than `end`.
+    ///
+    /// # Panics
+    /// Panics if `step` is not positive.
+    fn steps_by(start: Self, end: Self, step: Self) -> usize;
+
+    /// Returns how many of `start`, `start + step`, `start + step * 2`, ... are less than or
+    /// equal to `end`.
+    ///
+    /// # Panics
+    /// Panics if `step` is not positive.
+    fn inclusive_steps_by(start: Self, end: Self, step: Self) -> usize;
+
+    /// Returns `self + n`.
+    fn offset(self, n: usize) -> Self;
+
+    /// Returns `self + step * n`.
+    fn offset_by(self, n: usize, step: Self) -> Self;
+
+    /// Returns `self + n`, or `None` if it is not representable.
+    fn checked_offset(self, n: i128) -> Option<Self>;
 }
 
-pub trait PointRangeIteratorItem: Copy + Sized {
-    fn next(&mut self, range: &ops::Range<Self>) -> Option<Self>;
+const TOO_MANY_POINTS: &str = "range has more than usize::MAX points";
+
+// Differences are taken in the unsigned type of the same width, and offsets wrap around, so that
+// no intermediate value overflows even when the range spans the whole type.
+macro_rules! impl_integer_primitive {
+    ($($t:ty: $unsigned:ty),*) => {
+        $(
+            impl PointRangeIteratorPrimitive for $t {
+                fn steps(start: Self, end: Self) -> usize {
+                    if start < end {
+                        let distance = end.wrapping_sub(start) as $unsigned;
+                        usize::try_from(distance).expect(TOO_MANY_POINTS)
+                    } else {
+                        0
+                    }
+                }
+
+                fn steps_by(start: Self, end: Self, step: Self) -> usize {
+                    assert!(step > 0, "step must be positive");
+                    if start < end {
+                        let distance = end.wrapping_sub(start) as $unsigned;
+                        let step = step as $unsigned;
+                        let steps = distance / step + (distance % step != 0) as $unsigned;
+                        usize::try_from(steps).expect(TOO_MANY_POINTS)
+                    } else {
+                        0
+                    }
+                }
+
+                fn inclusive_steps_by(start: Self, end: Self, step: Self) -> usize {
+                    assert!(step > 0, "step must be positive");
+                    if start <= end {
+                        let distance = end.wrapping_sub(start) as $unsigned;
+                        usize::try_from(distance / step as $unsigned)
+                            .ok()
+                            .and_then(|steps| steps.checked_add(1))
+                            .expect(TOO_MANY_POINTS)
+                    } else {
+                        0
+                    }
+                }
+
+                fn offset(self, n: usize) -> Self {
+                    self.wrapping_add(n as Self)
+                }
+
+                fn offset_by(self, n: usize, step: Self) -> Self {
+                    self.wrapping_add((n as Self).wrapping_mul(step))
+                }
+
+                fn checked_offset(self, n: i128) -> Option<Self> {
+                    let distance = <$unsigned>::try_from(n.unsigned_abs()).ok()?;
+                    if n >= 0 && distance <= Self::MAX.wrapping_sub(self) as $unsigned {
+                        Some(self.wrapping_add(distance as Self))
+                    } else if n < 0 && distance <= self.wrapping_sub(Self::MIN) as $unsigned {
+                        Some(self.wrapping_sub(distance as Self))
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    };
 }
 
-impl<T: PointRangeIteratorPrimitive, U> PointRangeIteratorItem for euclid::Point2D<T, U> {
-    fn next(&mut self, range: &ops::Range<Self>) -> Option<Self> {
-        while self.y < range.end.y {
-            if self.x < range.end.x {
-                let result = Some(*self);
-                self.x = self.x + T::one();
-                return result;
+impl_integer_primitive!(
+    i8: u8,
+    i16: u16,
+    i32: u32,
+    i64: u64,
+    i128: u128,
+    isize: usize,
+    u8: u8,
+    u16: u16,
+    u32: u32,
+    u64: u64,
+    u128: u128,
+    usize: usize
+);
+
+macro_rules! impl_float_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl PointRangeIteratorPrimitive for $t {
+                fn steps(start: Self, end: Self) -> usize {
+                    if start < end {
+                        (end - start).ceil() as usize
+                    } else {
+                        0
+                    }
+                }
+
+                fn steps_by(start: Self, end: Self, step: Self) -> usize {
+                    assert!(step > 0.0, "step must be positive");
+                    if start < end {
+                        // Rounding may put the last step on or past `end`.
+                        let steps = ((end - start) / step).ceil() as usize;
+                        if steps > 0 && start.offset_by(steps - 1, step) >= end {
+                            steps - 1
+                        } else {
+                            steps
+                        }
+                    } else {
+                        0
+                    }
+                }
+
+                fn inclusive_steps_by(start: Self, end: Self, step: Self) -> usize {
+                    assert!(step > 0.0, "step must be positive");
+                    if start <= end {
+                        // Rounding may put the last step past `end`.
+                        let steps = ((end - start) / step).floor() as usize + 1;
+                        if start.offset_by(steps - 1, step) > end {
+                            steps - 1
+                        } else {
+                            steps
+                        }
+                    } else {
+                        0
+                    }
+                }
+
+                fn offset(self, n: usize) -> Self {
+                    self + n as Self
+                }
+
+                fn offset_by(self, n: usize, step: Self) -> Self {
+                    self + n as Self * step
+                }
+
+                // The sum overflows when it rounds to an infinity which `self` is not.
+                fn checked_offset(self, n: i128) -> Option<Self> {
+                    let sum = self + n as Self;
+                    if sum.is_finite() || !self.is_finite() {
+                        Some(sum)
+                    } else {
+                        None
+                    }
+                }
             }
-            *self = Self::new(range.start.x, self.y + T::one());
+        )*
+    };
+}
+
+impl_float_primitive!(f32, f64);
+
+/// Returns the offsets from `center` of the first and the last of `start..end`, or of
+/// `start..=end` if `end_inclusive`, along an axis.
+pub(crate) fn offset_bounds<T: PointRangeIteratorPrimitive>(
+    center: T,
+    start: T,
+    end: T,
+    end_inclusive: bool,
+) -> (i128, i128) {
+    let min = if start <= center {
+        1 - T::inclusive_steps_by(start, center, T::one()) as i128
+    } else {
+        T::steps(center, start) as i128
+    };
+    let max = if end_inclusive {
+        if center <= end {
+            T::inclusive_steps_by(center, end, T::one()) as i128 - 1
+        } else {
+            -(T::steps(end, center) as i128)
+        }
+    } else if center < end {
+        T::steps(center, end) as i128 - 1
+    } else {
+        -(T::inclusive_steps_by(end, center, T::one()) as i128)
+    };
+    (min, max)
+}
+
+struct Axes<'a, T> {
+    start: &'a [T],
+    end: &'a [T],
+    step: &'a [T],
+    end_inclusive: bool,
+    serpentine: bool,
+}
+
+impl<'a, T: PointRangeIteratorPrimitive> Axes<'a, T> {
+    fn count(&self, axis: usize) -> usize {
+        let (start, end, step) = (self.start[axis], self.end[axis], self.step[axis]);
+        if self.end_inclusive {
+            T::inclusive_steps_by(start, end, step)
+        } else {
+            T::steps_by(start, end, step)
         }
-        None
+    }
+
+    fn len(&self) -> usize {
+        (0..self.start.len())
+            .try_fold(1, |len: usize, axis| len.checked_mul(self.count(axis)))
+            .expect(TOO_MANY_POINTS)
+    }
+
+    // In serpentine order, an axis runs backwards whenever the index along the slower axes, taken
+    // as a whole, is odd, so consecutive points are always adjacent.
+    fn reflect(&self, axis: usize, axis_index: usize, slower_index: usize) -> usize {
+        if self.serpentine && slower_index % 2 == 1 {
+            self.count(axis) - 1 - axis_index
+        } else {
+            axis_index
+        }
+    }
+
+    fn get(&self, axes: &[usize], mut index: usize, point: &mut [T]) -> bool {
+        if index >= self.len() {
+            return false;
+        }
+        for &axis in axes {
+            let count = self.count(axis);
+            let axis_index = self.reflect(axis, index % count, index / count);
+            point[axis] = self.start[axis].offset_by(axis_index, self.step[axis]);
+            index /= count;
+        }
+        true
+    }
+
+    fn axis_index(&self, axis: usize, value: T) -> Option<usize> {
+        let (start, step) = (self.start[axis], self.step[axis]);
+        let index = if self.end_inclusive {
+            T::inclusive_steps_by(start, value, step).checked_sub(1)?
+        } else {
+            T::steps_by(start, value, step)
+        };
+        if index < self.count(axis) && start.offset_by(index, step) == value {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    fn index_of(&self, axes: &[usize], point: &[T]) -> Option<usize> {
+        axes.iter().rev().try_fold(0, |index, &axis| {
+            let axis_index = self.axis_index(axis, point[axis])?;
+            Some(index * self.count(axis) + self.reflect(axis, axis_index, index))
+        })
     }
 }
 
-impl<T: PointRangeIteratorPrimitive, U> PointRangeIteratorItem for euclid::Point3D<T, U> {
+pub trait PointRangeIteratorItem: Copy + Sized {
+    type Order: Copy + Default + fmt::Debug + Eq + hash::Hash;
+
+    type Step: Copy;
+
+    /// Returns the step of one along every axis.
+    fn unit_step() -> Self::Step;
+
+    /// Returns the number of points in `range`.
+    fn len(range: &PointRange<Self>) -> usize;
+
+    /// Returns the point at `index` in `order` over `range`.
+    fn get(range: &PointRange<Self>, order: Self::Order, index: usize) -> Option<Self>;
+
+    /// Returns the index of `self` in `order` over `range`.
+    fn index_of(&self, range: &PointRange<Self>, order: Self::Order) -> Option<usize>;
+
+    /// Returns the number of points along the axis which changes fastest in `order`.
+    fn row_len(range: &PointRange<Self>, order: Self::Order) -> usize;
+
+    /// Returns `point` moved to the `index`-th point along the axis which changes fastest in
+    /// `order`, ignoring `range.serpentine`.
+    fn with_row_index(
+        range: &PointRange<Self>,
+        order: Self::Order,
+        point: Self,
+        index: usize,
+    ) -> Self;
+
+    /// Returns `self` and moves it to the next point of `range` in the default order,
+    /// or returns `None` if `self` is not a point of `range`.
     fn next(&mut self, range: &ops::Range<Self>) -> Option<Self> {
-        while self.z < range.end.z {
-            while self.y < range.end.y {
-                if self.x < range.end.x {
-                    let result = Some(*self);
-                    self.x = self.x + T::one();
-                    return result;
+        let range = PointRange::new(range.clone());
+        let order = Self::Order::default();
+        let index = self.index_of(&range, order)?;
+        let current = *self;
+        *self = Self::get(&range, order, index + 1).unwrap_or(range.end);
+        Some(current)
+    }
+}
+
+macro_rules! impl_point_range_iterator_item {
+    ($point:ident, $vector:ident, $order:ident, $dimensions:literal) => {
+        impl<T: PointRangeIteratorPrimitive, U> PointRangeIteratorItem for euclid::$point<T, U> {
+            type Order = $order;
+
+            type Step = euclid::$vector<T, U>;
+
+            fn unit_step() -> Self::Step {
+                [T::one(); $dimensions].into()
+            }
+
+            fn len(range: &PointRange<Self>) -> usize {
+                let (start, end, step) = range.to_arrays();
+                range.axes(&start, &end, &step).len()
+            }
+
+            fn get(range: &PointRange<Self>, order: Self::Order, index: usize) -> Option<Self> {
+                let (start, end, step) = range.to_arrays();
+                let mut point = start;
+                if range
+                    .axes(&start, &end, &step)
+                    .get(&order.axes(), index, &mut point)
+                {
+                    Some(point.into())
+                } else {
+                    None
                 }
-                *self = Self::new(range.start.x, self.y + T::one(), self.z);
             }
-            *self = Self::new(range.start.x, range.start.y, self.z + T::one());
+
+            fn index_of(&self, range: &PointRange<Self>, order: Self::Order) -> Option<usize> {
+                let (start, end, step) = range.to_arrays();
+                range
+                    .axes(&start, &end, &step)
+                    .index_of(&order.axes(), &self.to_array())
+            }
+
+            fn row_len(range: &PointRange<Self>, order: Self::Order) -> usize {
+                range.counts()[order.axes()[0]]
+            }
+
+            fn with_row_index(
+                range: &PointRange<Self>,
+                order: Self::Order,
+                point: Self,
+                index: usize,
+            ) -> Self {
+                let axis = order.axes()[0];
+                let mut point = point.to_array();
+                point[axis] =
+                    range.start.to_array()[axis].offset_by(index, range.step.to_array()[axis]);
+                point.into()
+            }
         }
 
-        None
+        impl<T: PointRangeIteratorPrimitive, U> PointRange<euclid::$point<T, U>> {
+            fn to_arrays(&self) -> ([T; $dimensions], [T; $dimensions], [T; $dimensions]) {
+                (
+                    self.start.to_array(),
+                    self.end.to_array(),
+                    self.step.to_array(),
+                )
+            }
+
+            fn axes<'a>(&self, start: &'a [T], end: &'a [T], step: &'a [T]) -> Axes<'a, T> {
+                Axes {
+                    start,
+                    end,
+                    step,
+                    end_inclusive: self.end_inclusive,
+                    serpentine: self.serpentine,
+                }
+            }
+
+            /// Returns the number of points along each axis.
+            pub(crate) fn counts(&self) -> [usize; $dimensions] {
+                let (start, end, step) = self.to_arrays();
+                let axes = self.axes(&start, &end, &step);
+                let mut counts = [0; $dimensions];
+                for (axis, count) in counts.iter_mut().enumerate() {
+                    *count = axes.count(axis);
+                }
+                counts
+            }
+        }
+    };
+}
+
+impl_point_range_iterator_item!(Point2D, Vector2D, Order2D, 2);
+
+impl_point_range_iterator_item!(Point3D, Vector3D, Order3D, 3);
+
+/// The points from `start` to `end` at intervals of `step` along each axis.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PointRange<T: PointRangeIteratorItem> {
+    pub start: T,
+    pub end: T,
+    pub end_inclusive: bool,
+    pub step: T::Step,
+    /// Whether every other row runs backwards, and in 3D every other slab as well.
+    pub serpentine: bool,
+}
+
+impl<T: PointRangeIteratorItem> PointRange<T> {
+    pub fn new(range: ops::Range<T>) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+            end_inclusive: false,
+            step: T::unit_step(),
+            serpentine: false,
+        }
+    }
+
+    pub fn inclusive(range: ops::RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        Self {
+            start,
+            end,
+            end_inclusive: true,
+            step: T::unit_step(),
+            serpentine: false,
+        }
+    }
+
+    pub fn step_by(self, step: T::Step) -> Self {
+        Self { step, ..self }
+    }
+
+    pub fn serpentine(self) -> Self {
+        Self {
+            serpentine: true,
+            ..self
+        }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct PointRangeIterator<T: PointRangeIteratorItem> {
-    range: ops::Range<T>,
-    current: T,
+    // The fields of `PointRange` are kept inline so that the derives are bounded on `T::Step`.
+    start: T,
+    end: T,
+    end_inclusive: bool,
+    step: T::Step,
+    serpentine: bool,
+    order: T::Order,
+    front: usize,
+    back: usize,
+    row_len: usize,
+    /// The point at `front` and its index along the fastest axis, from which `next` steps along
+    /// the rows without computing the whole point from `front`.
+    current: Option<(T, usize)>,
 }
 
 impl<T: PointRangeIteratorItem> PointRangeIterator<T> {
     pub fn new(range: ops::Range<T>) -> Self {
-        let current = range.start;
-        Self { range, current }
+        Self::with_order(range, T::Order::default())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use euclid::Point2D;
+    /// # use euclid_ext::Order2D;
+    /// # use euclid_ext::point_range_iterator::PointRangeIterator;
+    /// enum Space {}
+    /// let mut i = PointRangeIterator::with_order(Point2D::<i64, Space>::new(10, 20)..Point2D::<i64, Space>::new(12, 22), Order2D::YX);
+    /// assert_eq!(Some(Point2D::new(10, 20)), i.next());
+    /// assert_eq!(Some(Point2D::new(10, 21)), i.next());
+    /// assert_eq!(Some(Point2D::new(11, 20)), i.next());
+    /// assert_eq!(Some(Point2D::new(11, 21)), i.next());
+    /// assert_eq!(None, i.next());
+    /// ```
+    pub fn with_order(range: ops::Range<T>, order: T::Order) -> Self {
+        Self::from_point_range(PointRange::new(range), order)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use euclid::{Point2D, Vector2D};
+    /// # use euclid_ext::point_range_iterator::PointRangeIterator;
+    /// enum Space {}
+    /// let mut i = PointRangeIterator::with_step(Point2D::<i64, Space>::new(10, 20)..Point2D::<i64, Space>::new(15, 22), Vector2D::new(3, 2));
+    /// assert_eq!(2, i.len());
+    /// assert_eq!(Some(Point2D::new(10, 20)), i.next());
+    /// assert_eq!(Some(Point2D::new(13, 20)), i.next());
+    /// assert_eq!(None, i.next());
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `step` is not positive along every axis.
+    pub fn with_step(range: ops::Range<T>, step: T::Step) -> Self {
+        Self::from_point_range(PointRange::new(range).step_by(step), T::Order::default())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use euclid::Point2D;
+    /// # use euclid_ext::point_range_iterator::PointRangeIterator;
+    /// enum Space {}
+    /// let mut i = PointRangeIterator::inclusive(Point2D::<u8, Space>::new(254, 20)..=Point2D::<u8, Space>::new(255, 21));
+    /// assert_eq!(Some(Point2D::new(254, 20)), i.next());
+    /// assert_eq!(Some(Point2D::new(255, 20)), i.next());
+    /// assert_eq!(Some(Point2D::new(254, 21)), i.next());
+    /// assert_eq!(Some(Point2D::new(255, 21)), i.next());
+    /// assert_eq!(None, i.next());
+    /// ```
+    pub fn inclusive(range: ops::RangeInclusive<T>) -> Self {
+        Self::from_point_range(PointRange::inclusive(range), T::Order::default())
+    }
+
+    /// Iterates the points in row-major order, reversing every other row so that consecutive
+    /// points are always adjacent.
+    ///
+    /// # Examples
+    /// ```
+    /// # use euclid::Point2D;
+    /// # use euclid_ext::point_range_iterator::PointRangeIterator;
+    /// enum Space {}
+    /// let mut i = PointRangeIterator::serpentine(Point2D::<i64, Space>::new(10, 20)..Point2D::<i64, Space>::new(12, 22));
+    /// assert_eq!(Some(Point2D::new(10, 20)), i.next());
+    /// assert_eq!(Some(Point2D::new(11, 20)), i.next());
+    /// assert_eq!(Some(Point2D::new(11, 21)), i.next());
+    /// assert_eq!(Some(Point2D::new(10, 21)), i.next());
+    /// assert_eq!(None, i.next());
+    /// ```
+    pub fn serpentine(range: ops::Range<T>) -> Self {
+        Self::from_point_range(PointRange::new(range).serpentine(), T::Order::default())
+    }
+
+    /// # Panics
+    /// Panics if `range.step` is not positive along every axis,
+    /// or if the range has more than `usize::MAX` points.
+    pub fn from_point_range(range: PointRange<T>, order: T::Order) -> Self {
+        let back = T::len(&range);
+        let row_len = T::row_len(&range, order);
+        let mut iter = Self {
+            start: range.start,
+            end: range.end,
+            end_inclusive: range.end_inclusive,
+            step: range.step,
+            serpentine: range.serpentine,
+            order,
+            front: 0,
+            back,
+            row_len,
+            current: None,
+        };
+        iter.seek();
+        iter
+    }
+
+    /// Computes `current` after `front` or `back` has moved.
+    fn seek(&mut self) {
+        self.current = if self.front < self.back {
+            T::get(&self.range(), self.order, self.front)
+                .map(|point| (point, self.front % self.row_len))
+        } else {
+            None
+        };
+    }
+
+    fn range(&self) -> PointRange<T> {
+        PointRange {
+            start: self.start,
+            end: self.end,
+            end_inclusive: self.end_inclusive,
+            step: self.step,
+            serpentine: self.serpentine,
+        }
+    }
+
+    /// Returns the `index`-th of the remaining points without advancing the iterator.
+    ///
+    /// # Examples
+    /// ```
+    /// # use euclid::Point3D;
+    /// # use euclid_ext::point_range_iterator::PointRangeIterator;
+    /// enum Space {}
+    /// let mut i = PointRangeIterator::new(Point3D::<i64, Space>::new(0, 0, 0)..Point3D::<i64, Space>::new(10, 10, 10));
+    /// assert_eq!(Some(Point3D::new(3, 2, 1)), i.get(123));
+    /// i.next();
+    /// assert_eq!(Some(Point3D::new(3, 2, 1)), i.get(122));
+    /// assert_eq!(None, i.get(999));
+    /// ```
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index < self.len() {
+            T::get(&self.range(), self.order, self.front + index)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the position of `point` among the remaining points.
+    ///
+    /// # Examples
+    /// ```
+    /// # use euclid::Point2D;
+    /// # use euclid_ext::point_range_iterator::PointRangeIterator;
+    /// enum Space {}
+    /// let mut i = PointRangeIterator::new(Point2D::<i64, Space>::new(0, 0)..Point2D::<i64, Space>::new(10, 10));
+    /// assert_eq!(Some(34), i.index_of(Point2D::new(4, 3)));
+    /// i.next();
+    /// assert_eq!(Some(33), i.index_of(Point2D::new(4, 3)));
+    /// assert_eq!(None, i.index_of(Point2D::new(0, 0)));
+    /// assert_eq!(None, i.index_of(Point2D::new(10, 3)));
+    /// ```
+    pub fn index_of(&self, point: T) -> Option<usize> {
+        let index = point
+            .index_of(&self.range(), self.order)?
+            .checked_sub(self.front)?;
+        if index < self.len() {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    /// Divides the remaining points into the first `index` points and the rest.
+    ///
+    /// # Examples
+    /// ```
+    /// # use euclid::Point2D;
+    /// # use euclid_ext::point_range_iterator::PointRangeIterator;
+    /// enum Space {}
+    /// let i = PointRangeIterator::new(Point2D::<i64, Space>::new(0, 0)..Point2D::<i64, Space>::new(2, 2));
+    /// let (left, right) = i.split_at(3);
+    /// assert_eq!(vec![Point2D::new(0, 0), Point2D::new(1, 0), Point2D::new(0, 1)], left.collect::<Vec<_>>());
+    /// assert_eq!(vec![Point2D::new(1, 1)], right.collect::<Vec<_>>());
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `index` is greater than the number of the remaining points.
+    pub fn split_at(self, index: usize) -> (Self, Self) {
+        assert!(index <= self.len(), "index out of bounds");
+        let middle = self.front + index;
+        let mut left = Self {
+            back: middle,
+            ..self.clone()
+        };
+        let mut right = Self {
+            front: middle,
+            ..self
+        };
+        left.seek();
+        right.seek();
+        (left, right)
     }
 }
 
@@ -75,6 +738,85 @@ impl<T: PointRangeIteratorItem> Iterator for PointRangeIterator<T> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.current.next(&self.range)
+        let (point, row_index) = self.current?;
+        self.front += 1;
+        // Points in the same row differ only along the fastest axis, except in serpentine order.
+        if self.front < self.back && !self.serpentine && row_index + 1 < self.row_len {
+            let next = T::with_row_index(&self.range(), self.order, point, row_index + 1);
+            self.current = Some((next, row_index + 1));
+        } else {
+            self.seek();
+        }
+        Some(point)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n > 0 {
+            self.front += n.min(self.len());
+            self.seek();
+        }
+        self.next()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
     }
 }
+
+/// # Examples
+/// ```
+/// # use euclid::Point2D;
+/// # use euclid_ext::point_range_iterator::PointRangeIterator;
+/// enum Space {}
+/// let mut i = PointRangeIterator::new(Point2D::<i64, Space>::new(10, 20)..Point2D::<i64, Space>::new(12, 22));
+/// assert_eq!(Some(Point2D::new(11, 21)), i.next_back());
+/// assert_eq!(Some(Point2D::new(10, 20)), i.next());
+/// assert_eq!(Some(Point2D::new(10, 21)), i.next_back());
+/// assert_eq!(Some(Point2D::new(11, 20)), i.next());
+/// assert_eq!(None, i.next_back());
+/// assert_eq!(None, i.next());
+/// ```
+impl<T: PointRangeIteratorItem> DoubleEndedIterator for PointRangeIterator<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            if self.front == self.back {
+                self.current = None;
+            }
+            T::get(&self.range(), self.order, self.back)
+        } else {
+            None
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.back -= n.min(self.len());
+        if self.front == self.back {
+            self.current = None;
+        }
+        self.next_back()
+    }
+}
+
+/// # Examples
+/// ```
+/// # use euclid::Point3D;
+/// # use euclid_ext::point_range_iterator::PointRangeIterator;
+/// enum Space {}
+/// let mut i = PointRangeIterator::new(Point3D::<i64, Space>::new(0, 0, 0)..Point3D::<i64, Space>::new(2, 3, 4));
+/// assert_eq!(24, i.len());
+/// i.next();
+/// assert_eq!(23, i.len());
+/// ```
+impl<T: PointRangeIteratorItem> ExactSizeIterator for PointRangeIterator<T> {}
+
+impl<T: PointRangeIteratorItem> iter::FusedIterator for PointRangeIterator<T> {}
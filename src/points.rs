@@ -1,5 +1,5 @@
 use crate::point_range_iterator::{PointRangeIterator, PointRangeIteratorItem};
-use crate::to_point_range::ToPointRange;
+use crate::to_point_range::IntoPointRange;
 
 /// # Examples
 /// ```
@@ -18,15 +18,81 @@ pub trait Points {
     type Iter: Iterator;
 
     fn points(self) -> Self::Iter;
+
+    /// # Examples
+    /// ```
+    /// # use euclid::{Box3D, Point3D};
+    /// # use euclid_ext::{Order3D, Points};
+    /// enum Space {}
+    /// let range = Box3D::new(Point3D::<i64, Space>::new(0, 0, 0), Point3D::<i64, Space>::new(2, 1, 2));
+    /// assert_eq!(
+    ///     vec![
+    ///         Point3D::new(0, 0, 0), Point3D::new(0, 0, 1),
+    ///         Point3D::new(1, 0, 0), Point3D::new(1, 0, 1)
+    ///     ],
+    ///     range.points_in_order(Order3D::ZXY).collect::<Vec<_>>());
+    /// ```
+    fn points_in_order<P: PointRangeIteratorItem>(self, order: P::Order) -> PointRangeIterator<P>
+    where
+        Self: IntoPointRange<Point = P> + Sized,
+    {
+        PointRangeIterator::from_point_range(self.into_point_range(), order)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use euclid::{Point2D, Rect, Size2D, Vector2D};
+    /// # use euclid_ext::Points;
+    /// enum Space {}
+    /// let range = Rect::new(Point2D::<f64, Space>::new(0.0, 1.0), Size2D::new(1.0, 0.5));
+    /// assert_eq!(
+    ///     vec![
+    ///         Point2D::new(0.0, 1.0), Point2D::new(0.4, 1.0), Point2D::new(0.8, 1.0),
+    ///         Point2D::new(0.0, 1.25), Point2D::new(0.4, 1.25), Point2D::new(0.8, 1.25)
+    ///     ],
+    ///     range.points_by(Vector2D::new(0.4, 0.25)).collect::<Vec<_>>());
+    /// ```
+    fn points_by<P: PointRangeIteratorItem>(self, step: P::Step) -> PointRangeIterator<P>
+    where
+        Self: IntoPointRange<Point = P> + Sized,
+    {
+        PointRangeIterator::from_point_range(
+            self.into_point_range().step_by(step),
+            P::Order::default(),
+        )
+    }
+
+    /// Iterates the points row by row, alternating the direction of the rows,
+    /// and in 3D alternating the direction of the slabs as well.
+    ///
+    /// # Examples
+    /// ```
+    /// # use euclid::{Box2D, Point2D};
+    /// # use euclid_ext::Points;
+    /// enum Space {}
+    /// let range = Box2D::new(Point2D::<i64, Space>::new(0, 0), Point2D::<i64, Space>::new(3, 2));
+    /// assert_eq!(
+    ///     vec![
+    ///         Point2D::new(0, 0), Point2D::new(1, 0), Point2D::new(2, 0),
+    ///         Point2D::new(2, 1), Point2D::new(1, 1), Point2D::new(0, 1)
+    ///     ],
+    ///     range.serpentine_points().collect::<Vec<_>>());
+    /// ```
+    fn serpentine_points<P: PointRangeIteratorItem>(self) -> PointRangeIterator<P>
+    where
+        Self: IntoPointRange<Point = P> + Sized,
+    {
+        PointRangeIterator::from_point_range(
+            self.into_point_range().serpentine(),
+            P::Order::default(),
+        )
+    }
 }
 
-impl<T: ToPointRange> Points for T
-where
-    T::Point: PointRangeIteratorItem,
-{
+impl<T: IntoPointRange> Points for T {
     type Iter = PointRangeIterator<T::Point>;
 
     fn points(self) -> Self::Iter {
-        PointRangeIterator::new(self.to_point_range())
+        PointRangeIterator::from_point_range(self.into_point_range(), Default::default())
     }
 }
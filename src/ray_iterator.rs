@@ -0,0 +1,252 @@
+use crate::point_range_iterator::{PointRange, PointRangeIteratorItem};
+use crate::to_point_range::IntoPointRange;
+use std::marker::PhantomData;
+use std::{fmt, iter};
+
+pub trait RayIteratorItem: Copy {
+    type Direction: Copy;
+
+    type Voxel: PointRangeIteratorItem + fmt::Debug + PartialEq;
+
+    type Normal: Copy + fmt::Debug + PartialEq;
+
+    /// Returns the coordinates of `self` and of `direction`, padded to three axes with a ray
+    /// that stays in the middle of a single layer of voxels.
+    fn ray_axes(self, direction: Self::Direction) -> ([f32; 3], [f32; 3]);
+
+    /// Returns the coordinates of the ends of `bounds`, padded to three axes with the single layer
+    /// of voxels. They are widened so that the end of an inclusive range is representable.
+    fn bounds_axes(bounds: &PointRange<Self::Voxel>) -> ([i64; 3], [i64; 3]);
+
+    fn voxel(axes: [i32; 3]) -> Self::Voxel;
+
+    /// Returns the unit normal pointing towards `sign` along `axis`.
+    fn normal(axis: usize, sign: i32) -> Self::Normal;
+}
+
+impl<U> RayIteratorItem for euclid::Point2D<f32, U> {
+    type Direction = euclid::Vector2D<f32, U>;
+
+    type Voxel = euclid::Point2D<i32, U>;
+
+    type Normal = euclid::Vector2D<i32, U>;
+
+    fn ray_axes(self, direction: Self::Direction) -> ([f32; 3], [f32; 3]) {
+        ([self.x, self.y, 0.5], [direction.x, direction.y, 0.0])
+    }
+
+    fn bounds_axes(bounds: &PointRange<Self::Voxel>) -> ([i64; 3], [i64; 3]) {
+        let (start, end) = (bounds.start.to_i64(), bounds.end.to_i64());
+        let end_offset = bounds.end_inclusive as i64;
+        (
+            [start.x, start.y, 0],
+            [end.x + end_offset, end.y + end_offset, 1],
+        )
+    }
+
+    fn voxel(axes: [i32; 3]) -> Self::Voxel {
+        Self::Voxel::new(axes[0], axes[1])
+    }
+
+    fn normal(axis: usize, sign: i32) -> Self::Normal {
+        let mut normal = [0; 2];
+        normal[axis] = sign;
+        normal.into()
+    }
+}
+
+impl<U> RayIteratorItem for euclid::Point3D<f32, U> {
+    type Direction = euclid::Vector3D<f32, U>;
+
+    type Voxel = euclid::Point3D<i32, U>;
+
+    type Normal = euclid::Vector3D<i32, U>;
+
+    fn ray_axes(self, direction: Self::Direction) -> ([f32; 3], [f32; 3]) {
+        (self.to_array(), direction.to_array())
+    }
+
+    fn bounds_axes(bounds: &PointRange<Self::Voxel>) -> ([i64; 3], [i64; 3]) {
+        let end_offset = bounds.end_inclusive as i64;
+        (
+            bounds.start.to_i64().to_array(),
+            bounds.end.to_i64().to_array().map(|end| end + end_offset),
+        )
+    }
+
+    fn voxel(axes: [i32; 3]) -> Self::Voxel {
+        axes.into()
+    }
+
+    fn normal(axis: usize, sign: i32) -> Self::Normal {
+        let mut normal = [0; 3];
+        normal[axis] = sign;
+        normal.into()
+    }
+}
+
+/// A voxel a ray passes through.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayVoxel<T: RayIteratorItem> {
+    pub voxel: T::Voxel,
+    /// The distance along the ray, in multiples of its direction, at which it enters the voxel.
+    pub t: f32,
+    /// The outward normal of the face through which the ray enters the voxel,
+    /// or `None` if the ray starts in the voxel.
+    pub face: Option<T::Normal>,
+}
+
+/// Iterates the voxels a ray passes through in order, using the algorithm of Amanatides and Woo.
+/// The voxel at `p` spans from `p` to `p + 1` along each axis.
+///
+/// When the ray crosses an edge or a corner exactly, it moves along the axes one at a time in
+/// the order x, y, z.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RayIterator<T: RayIteratorItem> {
+    voxel: [i64; 3],
+    step: [i64; 3],
+    t_max: [f32; 3],
+    t_delta: [f32; 3],
+    min: [i64; 3],
+    max: [i64; 3],
+    t: f32,
+    face: Option<T::Normal>,
+    done: bool,
+    phantom: PhantomData<T>,
+}
+
+impl<T: RayIteratorItem> RayIterator<T> {
+    /// Makes an iterator of the voxels in `bounds` which the ray from `origin` towards
+    /// `direction` passes through.
+    ///
+    /// # Examples
+    /// ```
+    /// # use euclid::{Point2D, Vector2D};
+    /// # use euclid_ext::ray_iterator::RayIterator;
+    /// enum Space {}
+    /// let bounds = Point2D::<i32, Space>::new(0, 0)..Point2D::new(10, 10);
+    /// let mut i = RayIterator::new(Point2D::new(-1.0, 0.25), Vector2D::new(1.0, 0.5), bounds);
+    /// let voxel = i.next().unwrap();
+    /// assert_eq!(Point2D::new(0, 0), voxel.voxel);
+    /// assert_eq!(1.0, voxel.t);
+    /// assert_eq!(Some(Vector2D::new(-1, 0)), voxel.face);
+    /// let voxel = i.next().unwrap();
+    /// assert_eq!(Point2D::new(0, 1), voxel.voxel);
+    /// assert_eq!(1.5, voxel.t);
+    /// assert_eq!(Some(Vector2D::new(0, -1)), voxel.face);
+    /// ```
+    pub fn new<B: IntoPointRange<Point = T::Voxel>>(
+        origin: T,
+        direction: T::Direction,
+        bounds: B,
+    ) -> Self {
+        let (origin, direction) = origin.ray_axes(direction);
+        let (min, max) = T::bounds_axes(&bounds.into_point_range());
+        let mut i = Self {
+            voxel: [0; 3],
+            step: [0; 3],
+            t_max: [f32::INFINITY; 3],
+            t_delta: [f32::INFINITY; 3],
+            min,
+            max,
+            t: 0.0,
+            face: None,
+            done: true,
+            phantom: PhantomData,
+        };
+        // Clips the ray to the bounds, one slab per axis.
+        let (mut enter, mut exit, mut enter_axis) = (0.0f32, f32::INFINITY, None);
+        for axis in 0..3 {
+            let (low, high) = (min[axis] as f32, max[axis] as f32);
+            if min[axis] >= max[axis] {
+                return i;
+            }
+            if direction[axis] == 0.0 {
+                if origin[axis] < low || origin[axis] >= high {
+                    return i;
+                }
+                continue;
+            }
+            let t1 = (low - origin[axis]) / direction[axis];
+            let t2 = (high - origin[axis]) / direction[axis];
+            let (near, far) = if t1 < t2 { (t1, t2) } else { (t2, t1) };
+            if near > enter {
+                enter = near;
+                enter_axis = Some(axis);
+            }
+            exit = exit.min(far);
+        }
+        if enter >= exit {
+            return i;
+        }
+        for axis in 0..3 {
+            let d = direction[axis];
+            i.step[axis] = if d > 0.0 {
+                1
+            } else if d < 0.0 {
+                -1
+            } else {
+                0
+            };
+            i.voxel[axis] = if Some(axis) == enter_axis {
+                if d > 0.0 {
+                    min[axis]
+                } else {
+                    max[axis] - 1
+                }
+            } else {
+                ((origin[axis] + d * enter).floor() as i64).clamp(min[axis], max[axis] - 1)
+            };
+            if d != 0.0 {
+                let boundary = i.voxel[axis] + (d > 0.0) as i64;
+                i.t_max[axis] = (boundary as f32 - origin[axis]) / d;
+                i.t_delta[axis] = 1.0 / d.abs();
+            }
+        }
+        i.t = enter;
+        i.face = enter_axis.map(|axis| T::normal(axis, -i.step[axis] as i32));
+        i.done = false;
+        i
+    }
+
+    fn advance(&mut self) {
+        let mut axis = 0;
+        for other in 1..3 {
+            if self.t_max[other] < self.t_max[axis] {
+                axis = other;
+            }
+        }
+        if self.t_max[axis].is_infinite() {
+            self.done = true;
+            return;
+        }
+        self.voxel[axis] += self.step[axis];
+        if self.voxel[axis] < self.min[axis] || self.voxel[axis] >= self.max[axis] {
+            self.done = true;
+            return;
+        }
+        self.t = self.t_max[axis];
+        self.t_max[axis] += self.t_delta[axis];
+        self.face = Some(T::normal(axis, -self.step[axis] as i32));
+    }
+}
+
+impl<T: RayIteratorItem> Iterator for RayIterator<T> {
+    type Item = RayVoxel<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let voxel = RayVoxel {
+            // The voxel is in the bounds, so it is representable.
+            voxel: T::voxel(self.voxel.map(|coordinate| coordinate as i32)),
+            t: self.t,
+            face: self.face,
+        };
+        self.advance();
+        Some(voxel)
+    }
+}
+
+impl<T: RayIteratorItem> iter::FusedIterator for RayIterator<T> {}
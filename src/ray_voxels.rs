@@ -0,0 +1,33 @@
+use crate::ray_iterator::{RayIterator, RayIteratorItem};
+use crate::to_point_range::IntoPointRange;
+
+/// # Examples
+/// ```
+/// # use euclid::{Box3D, Point3D, Vector3D};
+/// # use euclid_ext::RayVoxels;
+/// enum Space {}
+/// let bounds = Box3D::new(Point3D::<i32, Space>::new(0, 0, 0), Point3D::new(4, 4, 4));
+/// let origin = Point3D::<f32, Space>::new(0.5, 0.5, 0.5);
+/// assert_eq!(
+///     vec![Point3D::new(0, 0, 0), Point3D::new(0, 0, 1), Point3D::new(0, 0, 2), Point3D::new(0, 0, 3)],
+///     origin.ray_voxels(Vector3D::new(0.0, 0.0, 2.0), bounds).map(|v| v.voxel).collect::<Vec<_>>());
+/// ```
+pub trait RayVoxels: RayIteratorItem {
+    /// Iterates the voxels in `bounds` which the ray from `self` towards `direction` passes
+    /// through.
+    fn ray_voxels<B: IntoPointRange<Point = Self::Voxel>>(
+        self,
+        direction: Self::Direction,
+        bounds: B,
+    ) -> RayIterator<Self>;
+}
+
+impl<T: RayIteratorItem> RayVoxels for T {
+    fn ray_voxels<B: IntoPointRange<Point = Self::Voxel>>(
+        self,
+        direction: Self::Direction,
+        bounds: B,
+    ) -> RayIterator<Self> {
+        RayIterator::new(self, direction, bounds)
+    }
+}
@@ -0,0 +1,582 @@
+use crate::shape_iterator::ShapeIteratorItem;
+use std::ops;
+
+fn isqrt(n: i64) -> i64 {
+    if n <= 0 {
+        return 0;
+    }
+    // The square root in `f64` is off by at most one, and the squares fit in `i128`.
+    let n = i128::from(n);
+    let mut root = (n as f64).sqrt() as i128;
+    while root * root > n {
+        root -= 1;
+    }
+    while (root + 1) * (root + 1) <= n {
+        root += 1;
+    }
+    root as i64
+}
+
+fn ceil_isqrt(n: i64) -> i64 {
+    let root = isqrt(n);
+    if root * root < n {
+        root + 1
+    } else {
+        root
+    }
+}
+
+/// Returns the largest `x` with `x^2 - x < n`, for a positive `n`.
+fn midpoint_isqrt(n: i64) -> i64 {
+    let root = isqrt(n);
+    if root * (root + 1) < n {
+        root + 1
+    } else {
+        root
+    }
+}
+
+/// Converts `value` to the nearest value in the range of `i32`.
+fn saturate(value: i64) -> i32 {
+    value.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32
+}
+
+/// Returns the span of the cells from `center - low` to `center + high`, clipped to the range of
+/// `i32`.
+fn span(center: i32, low: i64, high: i64) -> ops::Range<i32> {
+    let center = i64::from(center);
+    saturate(center + low)..saturate(center + high + 1)
+}
+
+/// Returns the span of the cells from `center - half_width` to `center + half_width`, clipped to
+/// the range of `i32`.
+fn centered_span(center: i32, half_width: i64) -> ops::Range<i32> {
+    span(center, -half_width, half_width)
+}
+
+/// Returns the smallest and largest distances from the center along the x axis of the cells the
+/// midpoint circle algorithm draws in the row `y` away from the center.
+fn midpoint_row(radius: i64, y: i64) -> Option<(i64, i64)> {
+    // In the octant from the top of the circle, the algorithm moves to the row below once the
+    // midpoint between the rows is no longer inside the circle, so it draws `(x, y)` where `y` is
+    // the largest with `x^2 + y^2 - y < radius^2`. The other octants are reflections of it.
+    let y = y.abs();
+    if radius < 0 || y > radius {
+        return None;
+    }
+    if radius == 0 {
+        return Some((0, 0));
+    }
+    let rest = radius * radius - y * y;
+    // The cells drawn by the octants next to the y axis, where `y` is the larger distance.
+    let mut low = ceil_isqrt(rest - y);
+    let mut high = if rest + y > 0 {
+        isqrt(rest + y - 1).min(y)
+    } else {
+        -1
+    };
+    // The cell drawn by the octants next to the x axis, where `x` is the larger distance.
+    if rest > 0 {
+        let x = midpoint_isqrt(rest);
+        if y <= x {
+            if low > high {
+                low = x;
+                high = x;
+            } else {
+                low = low.min(x);
+                high = high.max(x);
+            }
+        }
+    }
+    if low <= high {
+        Some((low, high))
+    } else {
+        None
+    }
+}
+
+/// Returns the largest distance from the center along the x axis of the cells on or inside the
+/// midpoint circle in a row whose other coordinates are `offsets` away from the center. With
+/// `squares` the sum of their squares and `largest` their largest magnitude, the cells are those
+/// with `x^2 + squares - max(x, largest)` less than `radius^2`.
+fn filled_row(radius: i64, offsets: &[i64]) -> Option<i64> {
+    let largest = offsets.iter().map(|offset| offset.abs()).max().unwrap_or(0);
+    if radius < 0 || largest > radius {
+        return None;
+    }
+    // The offsets are at most `radius`, so their squares do not overflow.
+    let squares = offsets.iter().map(|offset| offset * offset).sum::<i64>();
+    if radius == 0 {
+        return Some(0);
+    }
+    let rest = radius * radius - squares;
+    if rest > 0 && midpoint_isqrt(rest) >= largest {
+        return Some(midpoint_isqrt(rest));
+    }
+    if rest + largest > 0 {
+        Some(isqrt(rest + largest - 1).min(largest))
+    } else {
+        None
+    }
+}
+
+/// An edge of a polygon which is not horizontal, in `i128` since the products of coordinates and
+/// heights can reach `2^64`.
+#[derive(Debug, Clone)]
+struct Edge {
+    top_x: i128,
+    top_y: i128,
+    width: i128,
+    height: i128,
+    winding: i32,
+}
+
+/// The edges of a polygon sorted by their top rows, with the edges crossing the last row scanned.
+#[derive(Debug, Clone)]
+pub struct PolygonScan {
+    edges: Vec<Edge>,
+    next: usize,
+    active: Vec<usize>,
+    crossings: Vec<(i128, i32)>,
+    y: i128,
+}
+
+impl PolygonScan {
+    fn new<U>(vertices: &[euclid::Point2D<i32, U>]) -> Self {
+        let mut edges = vertices
+            .iter()
+            .enumerate()
+            .filter_map(|(index, &a)| {
+                let b = vertices[(index + 1) % vertices.len()];
+                let (top, bottom, winding) = if a.y < b.y { (a, b, 1) } else { (b, a, -1) };
+                if top.y == bottom.y {
+                    return None;
+                }
+                Some(Edge {
+                    top_x: i128::from(top.x),
+                    top_y: i128::from(top.y),
+                    width: i128::from(bottom.x) - i128::from(top.x),
+                    height: i128::from(bottom.y) - i128::from(top.y),
+                    winding,
+                })
+            })
+            .collect::<Vec<_>>();
+        edges.sort_unstable_by_key(|edge| edge.top_y);
+        Self {
+            edges,
+            next: 0,
+            active: Vec::new(),
+            crossings: Vec::new(),
+            y: i128::MIN,
+        }
+    }
+
+    /// Appends the spans of the cells in the row `y` whose points are inside the polygon.
+    ///
+    /// A point on the boundary is inside when the polygon lies to its right, or below it along a
+    /// horizontal edge, so that polygons sharing an edge never cover the same cell.
+    fn spans(&mut self, fill_rule: FillRule, y: i32, spans: &mut Vec<ops::Range<i32>>) {
+        let y = i128::from(y);
+        // The rows are usually scanned downwards, so the edges are only searched again upwards.
+        if y < self.y {
+            self.next = 0;
+            self.active.clear();
+        }
+        self.y = y;
+        while let Some(edge) = self.edges.get(self.next) {
+            if edge.top_y > y {
+                break;
+            }
+            self.active.push(self.next);
+            self.next += 1;
+        }
+        let edges = &self.edges;
+        self.active
+            .retain(|&index| y < edges[index].top_y + edges[index].height);
+        self.crossings.clear();
+        for &index in &self.active {
+            // The first x at or to the right of where the edge crosses the row.
+            let edge = &edges[index];
+            let numerator = edge.top_x * edge.height + (y - edge.top_y) * edge.width;
+            self.crossings
+                .push((-(-numerator).div_euclid(edge.height), edge.winding));
+        }
+        self.crossings.sort_unstable();
+        let mut winding = 0;
+        for pair in self.crossings.windows(2) {
+            winding += pair[0].1;
+            let (start, end) = (pair[0].0, pair[1].0);
+            if start == end || !fill_rule.is_inside(winding) {
+                continue;
+            }
+            match spans.last_mut() {
+                Some(last) if i128::from(last.end) == start => last.end = end as i32,
+                _ => spans.push(start as i32..end as i32),
+            }
+        }
+    }
+}
+
+/// Returns a range containing all of `vertices`.
+fn vertex_range<U>(vertices: &[euclid::Point2D<i32, U>]) -> ops::Range<euclid::Point2D<i32, U>> {
+    match vertices.split_first() {
+        Some((&first, rest)) => {
+            let (min, max) = rest.iter().fold((first, first), |(min, max), &vertex| {
+                (min.min(vertex), max.max(vertex))
+            });
+            // The cells at the maximum of `i32` are left out, like those of the other shapes.
+            min..euclid::Point2D::new(max.x.saturating_add(1), max.y.saturating_add(1))
+        }
+        None => euclid::Point2D::origin()..euclid::Point2D::origin(),
+    }
+}
+
+/// A set of cells which can be iterated row by row.
+pub trait Shape {
+    type Point: ShapeIteratorItem;
+
+    /// What `spans` reuses from row to row, such as the edges of a polygon.
+    type Scan: Clone;
+
+    /// Returns a range containing all the cells.
+    ///
+    /// The range is clipped to the range of `i32`, and ends before its maximum, so the cells at the
+    /// maximum are left out.
+    fn bounding_range(&self) -> ops::Range<Self::Point>;
+
+    /// Returns what `spans` reuses from row to row, built once for all the rows.
+    fn scan(&self) -> Self::Scan;
+
+    /// Appends the cells in the row of `row` to `spans` as ranges along the x axis, in order and
+    /// without overlaps. The x coordinate of `row` is ignored.
+    fn spans(&self, scan: &mut Self::Scan, row: Self::Point, spans: &mut Vec<ops::Range<i32>>);
+}
+
+/// The outline of a circle drawn by the midpoint circle algorithm.
+///
+/// # Examples
+/// ```
+/// # use euclid::Point2D;
+/// # use euclid_ext::{Circle, ShapePoints};
+/// enum Space {}
+/// assert_eq!(
+///     vec![
+///         Point2D::new(-1, -2), Point2D::new(0, -2), Point2D::new(1, -2),
+///         Point2D::new(-2, -1), Point2D::new(2, -1),
+///         Point2D::new(-2, 0), Point2D::new(2, 0),
+///         Point2D::new(-2, 1), Point2D::new(2, 1),
+///         Point2D::new(-1, 2), Point2D::new(0, 2), Point2D::new(1, 2)
+///     ],
+///     Circle::new(Point2D::<i32, Space>::new(0, 0), 2).shape_points().collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Circle<T> {
+    pub center: T,
+    pub radius: i32,
+}
+
+impl<U> Circle<euclid::Point2D<i32, U>> {
+    pub fn new(center: euclid::Point2D<i32, U>, radius: i32) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl<U> Shape for Circle<euclid::Point2D<i32, U>> {
+    type Point = euclid::Point2D<i32, U>;
+
+    type Scan = ();
+
+    fn bounding_range(&self) -> ops::Range<Self::Point> {
+        let radius = i64::from(self.radius);
+        let x = centered_span(self.center.x, radius);
+        let y = centered_span(self.center.y, radius);
+        euclid::Point2D::new(x.start, y.start)..euclid::Point2D::new(x.end, y.end)
+    }
+
+    fn scan(&self) -> Self::Scan {}
+
+    fn spans(&self, _: &mut Self::Scan, row: Self::Point, spans: &mut Vec<ops::Range<i32>>) {
+        let y = i64::from(row.y) - i64::from(self.center.y);
+        match midpoint_row(i64::from(self.radius), y) {
+            Some((0, high)) => spans.push(centered_span(self.center.x, high)),
+            Some((low, high)) => {
+                spans.push(span(self.center.x, -high, -low));
+                spans.push(span(self.center.x, low, high));
+            }
+            None => {}
+        }
+    }
+}
+
+/// The cells of a `Circle` of the same center and radius and the cells inside it.
+///
+/// # Examples
+/// ```
+/// # use euclid::Point2D;
+/// # use euclid_ext::{Disk, ShapePoints};
+/// enum Space {}
+/// assert_eq!(
+///     vec![
+///         Point2D::new(0, -1),
+///         Point2D::new(-1, 0), Point2D::new(0, 0), Point2D::new(1, 0),
+///         Point2D::new(0, 1)
+///     ],
+///     Disk::new(Point2D::<i32, Space>::new(0, 0), 1).shape_points().collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Disk<T> {
+    pub center: T,
+    pub radius: i32,
+}
+
+impl<U> Disk<euclid::Point2D<i32, U>> {
+    pub fn new(center: euclid::Point2D<i32, U>, radius: i32) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl<U> Shape for Disk<euclid::Point2D<i32, U>> {
+    type Point = euclid::Point2D<i32, U>;
+
+    type Scan = ();
+
+    fn bounding_range(&self) -> ops::Range<Self::Point> {
+        let radius = i64::from(self.radius);
+        let x = centered_span(self.center.x, radius);
+        let y = centered_span(self.center.y, radius);
+        euclid::Point2D::new(x.start, y.start)..euclid::Point2D::new(x.end, y.end)
+    }
+
+    fn scan(&self) -> Self::Scan {}
+
+    fn spans(&self, _: &mut Self::Scan, row: Self::Point, spans: &mut Vec<ops::Range<i32>>) {
+        let y = i64::from(row.y) - i64::from(self.center.y);
+        if let Some(half_width) = filled_row(i64::from(self.radius), &[y]) {
+            spans.push(centered_span(self.center.x, half_width));
+        }
+    }
+}
+
+/// The cells whose centers are within the axis-aligned ellipse with radii half a cell longer than
+/// `radius_x` and `radius_y`.
+///
+/// # Examples
+/// ```
+/// # use euclid::{Point2D, Size2D};
+/// # use euclid_ext::{Ellipse, ShapePoints};
+/// enum Space {}
+/// assert_eq!(
+///     vec![
+///         Point2D::new(-1, -1), Point2D::new(0, -1), Point2D::new(1, -1),
+///         Point2D::new(-2, 0), Point2D::new(-1, 0), Point2D::new(0, 0), Point2D::new(1, 0), Point2D::new(2, 0),
+///         Point2D::new(-1, 1), Point2D::new(0, 1), Point2D::new(1, 1)
+///     ],
+///     Ellipse::new(Point2D::<i32, Space>::new(0, 0), Size2D::new(2, 1)).shape_points().collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ellipse<T> {
+    pub center: T,
+    pub radius_x: i32,
+    pub radius_y: i32,
+}
+
+impl<U> Ellipse<euclid::Point2D<i32, U>> {
+    pub fn new(center: euclid::Point2D<i32, U>, radii: euclid::Size2D<i32, U>) -> Self {
+        Self {
+            center,
+            radius_x: radii.width,
+            radius_y: radii.height,
+        }
+    }
+}
+
+impl<U> Shape for Ellipse<euclid::Point2D<i32, U>> {
+    type Point = euclid::Point2D<i32, U>;
+
+    type Scan = ();
+
+    fn bounding_range(&self) -> ops::Range<Self::Point> {
+        let x = centered_span(self.center.x, i64::from(self.radius_x));
+        let y = centered_span(self.center.y, i64::from(self.radius_y));
+        euclid::Point2D::new(x.start, y.start)..euclid::Point2D::new(x.end, y.end)
+    }
+
+    fn scan(&self) -> Self::Scan {}
+
+    fn spans(&self, _: &mut Self::Scan, row: Self::Point, spans: &mut Vec<ops::Range<i32>>) {
+        // `(2x / a)^2 + (2y / b)^2 <= 1` where `a` and `b` are the diameters.
+        let a = 2 * i128::from(self.radius_x) + 1;
+        let b = 2 * i128::from(self.radius_y) + 1;
+        let y = 2 * (i128::from(row.y) - i128::from(self.center.y));
+        if self.radius_x < 0 || self.radius_y < 0 || y * y > b * b {
+            return;
+        }
+        // The diameters are less than `2^32`, so the products fit in `u128`.
+        let (a, b, y) = (a as u128, b as u128, y.unsigned_abs());
+        let rest = a * a * (b * b - y * y) / (4 * b * b);
+        spans.push(centered_span(self.center.x, isqrt(rest as i64)));
+    }
+}
+
+/// The cells with `x^2 + y^2 + z^2 - max(|x|, |y|, |z|) < radius^2` relative to the center,
+/// which extends the cells a `Disk` covers, so that the slice through the center is a `Disk`.
+///
+/// # Examples
+/// ```
+/// # use euclid::Point3D;
+/// # use euclid_ext::{Ball, ShapePoints};
+/// enum Space {}
+/// assert_eq!(7, Ball::new(Point3D::<i32, Space>::new(0, 0, 0), 1).shape_points().count());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ball<T> {
+    pub center: T,
+    pub radius: i32,
+}
+
+impl<U> Ball<euclid::Point3D<i32, U>> {
+    pub fn new(center: euclid::Point3D<i32, U>, radius: i32) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl<U> Shape for Ball<euclid::Point3D<i32, U>> {
+    type Point = euclid::Point3D<i32, U>;
+
+    type Scan = ();
+
+    fn bounding_range(&self) -> ops::Range<Self::Point> {
+        let radius = i64::from(self.radius);
+        let x = centered_span(self.center.x, radius);
+        let y = centered_span(self.center.y, radius);
+        let z = centered_span(self.center.z, radius);
+        euclid::Point3D::new(x.start, y.start, z.start)..euclid::Point3D::new(x.end, y.end, z.end)
+    }
+
+    fn scan(&self) -> Self::Scan {}
+
+    fn spans(&self, _: &mut Self::Scan, row: Self::Point, spans: &mut Vec<ops::Range<i32>>) {
+        let y = i64::from(row.y) - i64::from(self.center.y);
+        let z = i64::from(row.z) - i64::from(self.center.z);
+        if let Some(half_width) = filled_row(i64::from(self.radius), &[y, z]) {
+            spans.push(centered_span(self.center.x, half_width));
+        }
+    }
+}
+
+/// Ways of deciding which points a polygon covers from how many times its boundary winds around
+/// them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FillRule {
+    /// The points the boundary winds around any number of times other than zero.
+    #[default]
+    NonZero,
+    /// The points the boundary winds around an odd number of times.
+    EvenOdd,
+}
+
+impl FillRule {
+    pub(crate) fn is_inside(self, winding: i32) -> bool {
+        match self {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+}
+
+/// The cells whose points are inside a triangle, following the top-left convention, so that
+/// triangles sharing an edge never cover the same cell. The y axis is taken to point down.
+///
+/// # Examples
+/// ```
+/// # use euclid::Point2D;
+/// # use euclid_ext::{ShapePoints, Triangle};
+/// enum Space {}
+/// let triangle = Triangle::new(Point2D::<i32, Space>::new(0, 0), Point2D::new(2, 0), Point2D::new(0, 2));
+/// assert_eq!(
+///     vec![Point2D::new(0, 0), Point2D::new(1, 0), Point2D::new(0, 1)],
+///     triangle.shape_points().collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Triangle<T> {
+    pub vertices: [T; 3],
+}
+
+impl<U> Triangle<euclid::Point2D<i32, U>> {
+    pub fn new(
+        a: euclid::Point2D<i32, U>,
+        b: euclid::Point2D<i32, U>,
+        c: euclid::Point2D<i32, U>,
+    ) -> Self {
+        Self {
+            vertices: [a, b, c],
+        }
+    }
+}
+
+impl<U> Shape for Triangle<euclid::Point2D<i32, U>> {
+    type Point = euclid::Point2D<i32, U>;
+
+    type Scan = PolygonScan;
+
+    fn bounding_range(&self) -> ops::Range<Self::Point> {
+        vertex_range(&self.vertices)
+    }
+
+    fn scan(&self) -> Self::Scan {
+        PolygonScan::new(&self.vertices)
+    }
+
+    fn spans(&self, scan: &mut Self::Scan, row: Self::Point, spans: &mut Vec<ops::Range<i32>>) {
+        scan.spans(FillRule::NonZero, row.y, spans);
+    }
+}
+
+/// The cells whose points are inside a polygon under a fill rule, following the top-left
+/// convention like `Triangle`. The polygon may intersect itself.
+///
+/// # Examples
+/// ```
+/// # use euclid::Point2D;
+/// # use euclid_ext::{FillRule, Polygon, ShapePoints};
+/// enum Space {}
+/// let vertices = vec![
+///     Point2D::<i32, Space>::new(0, 0), Point2D::new(3, 0), Point2D::new(3, 3), Point2D::new(0, 3),
+///     Point2D::new(0, 0), Point2D::new(1, 1), Point2D::new(2, 1), Point2D::new(2, 2),
+///     Point2D::new(1, 2), Point2D::new(1, 1),
+/// ];
+/// assert_eq!(9, Polygon::new(vertices.clone(), FillRule::NonZero).shape_points().count());
+/// assert_eq!(8, Polygon::new(vertices, FillRule::EvenOdd).shape_points().count());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon<T> {
+    pub vertices: Vec<T>,
+    pub fill_rule: FillRule,
+}
+
+impl<U> Polygon<euclid::Point2D<i32, U>> {
+    pub fn new(vertices: Vec<euclid::Point2D<i32, U>>, fill_rule: FillRule) -> Self {
+        Self {
+            vertices,
+            fill_rule,
+        }
+    }
+}
+
+impl<U> Shape for Polygon<euclid::Point2D<i32, U>> {
+    type Point = euclid::Point2D<i32, U>;
+
+    type Scan = PolygonScan;
+
+    fn bounding_range(&self) -> ops::Range<Self::Point> {
+        vertex_range(&self.vertices)
+    }
+
+    fn scan(&self) -> Self::Scan {
+        PolygonScan::new(&self.vertices)
+    }
+
+    fn spans(&self, scan: &mut Self::Scan, row: Self::Point, spans: &mut Vec<ops::Range<i32>>) {
+        scan.spans(self.fill_rule, row.y, spans);
+    }
+}
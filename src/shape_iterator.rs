@@ -0,0 +1,237 @@
+use crate::point_range_iterator::{PointRange, PointRangeIterator, PointRangeIteratorItem};
+use crate::shape::Shape;
+use crate::to_point_range::IntoPointRange;
+use std::cell::Cell;
+use std::{fmt, iter, ops};
+
+pub trait ShapeIteratorItem: PointRangeIteratorItem {
+    /// Returns the part of `range` within `bounds`.
+    fn clip(range: ops::Range<Self>, bounds: &PointRange<Self>) -> ops::Range<Self>;
+
+    /// Returns the first point of each row of `range`.
+    fn rows(range: &ops::Range<Self>) -> ops::Range<Self>;
+
+    fn x(self) -> i32;
+
+    fn with_x(self, x: i32) -> Self;
+}
+
+macro_rules! impl_shape_iterator_item {
+    ($point:ident) => {
+        impl<U> ShapeIteratorItem for euclid::$point<i32, U> {
+            fn clip(range: ops::Range<Self>, bounds: &PointRange<Self>) -> ops::Range<Self> {
+                let (mut end, bounds_end) = (range.end.to_array(), bounds.end.to_array());
+                for axis in 0..end.len() {
+                    // An inclusive end at `i32::MAX` does not clip the range.
+                    let bound = if bounds.end_inclusive {
+                        bounds_end[axis].checked_add(1)
+                    } else {
+                        Some(bounds_end[axis])
+                    };
+                    if let Some(bound) = bound {
+                        end[axis] = end[axis].min(bound);
+                    }
+                }
+                range.start.max(bounds.start)..end.into()
+            }
+
+            fn rows(range: &ops::Range<Self>) -> ops::Range<Self> {
+                let x = if range.start.x < range.end.x {
+                    range.start.x + 1
+                } else {
+                    range.start.x
+                };
+                range.start..range.end.with_x(x)
+            }
+
+            fn x(self) -> i32 {
+                self.x
+            }
+
+            fn with_x(self, x: i32) -> Self {
+                Self { x, ..self }
+            }
+        }
+    };
+}
+
+impl_shape_iterator_item!(Point2D);
+
+impl_shape_iterator_item!(Point3D);
+
+/// Iterates the cells of a shape in row-major order.
+#[derive(Clone)]
+pub struct ShapeIterator<S: Shape> {
+    shape: S,
+    scan: S::Scan,
+    rows: PointRangeIterator<S::Point>,
+    x: ops::Range<i32>,
+    row: S::Point,
+    spans: Vec<ops::Range<i32>>,
+    span: usize,
+    // The number of cells left, counted on the first call to `size_hint`.
+    len: Cell<Option<usize>>,
+}
+
+impl<S: Shape + fmt::Debug> fmt::Debug for ShapeIterator<S>
+where
+    S::Point: fmt::Debug,
+    S::Scan: fmt::Debug,
+    PointRangeIterator<S::Point>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ShapeIterator")
+            .field("shape", &self.shape)
+            .field("scan", &self.scan)
+            .field("rows", &self.rows)
+            .field("x", &self.x)
+            .field("row", &self.row)
+            .field("spans", &self.spans)
+            .field("span", &self.span)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<S: Shape + PartialEq> PartialEq for ShapeIterator<S>
+where
+    S::Point: PartialEq,
+    PointRangeIterator<S::Point>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.shape == other.shape
+            && self.rows == other.rows
+            && self.x == other.x
+            && self.row == other.row
+            && self.spans == other.spans
+            && self.span == other.span
+    }
+}
+
+impl<S: Shape> ShapeIterator<S> {
+    /// # Examples
+    /// ```
+    /// # use euclid::Point2D;
+    /// # use euclid_ext::Disk;
+    /// # use euclid_ext::shape_iterator::ShapeIterator;
+    /// enum Space {}
+    /// let mut i = ShapeIterator::new(Disk::new(Point2D::<i32, Space>::new(10, 20), 1));
+    /// assert_eq!(5, i.len());
+    /// assert_eq!(Some(Point2D::new(10, 19)), i.next());
+    /// assert_eq!(Some(Point2D::new(9, 20)), i.next());
+    /// assert_eq!(Some(Point2D::new(10, 20)), i.next());
+    /// ```
+    pub fn new(shape: S) -> Self {
+        let range = shape.bounding_range();
+        Self::from_range(shape, range)
+    }
+
+    /// Iterates the cells of `shape` in `bounds`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use euclid::Point2D;
+    /// # use euclid_ext::Disk;
+    /// # use euclid_ext::shape_iterator::ShapeIterator;
+    /// enum Space {}
+    /// let bounds = Point2D::<i32, Space>::new(0, 0)..Point2D::new(10, 10);
+    /// let mut i = ShapeIterator::with_bounds(Disk::new(Point2D::new(0, 0), 1), bounds);
+    /// assert_eq!(Some(Point2D::new(0, 0)), i.next());
+    /// assert_eq!(Some(Point2D::new(1, 0)), i.next());
+    /// assert_eq!(Some(Point2D::new(0, 1)), i.next());
+    /// assert_eq!(None, i.next());
+    /// ```
+    pub fn with_bounds<B: IntoPointRange<Point = S::Point>>(shape: S, bounds: B) -> Self {
+        let range = S::Point::clip(shape.bounding_range(), &bounds.into_point_range());
+        Self::from_range(shape, range)
+    }
+
+    fn from_range(shape: S, range: ops::Range<S::Point>) -> Self {
+        Self {
+            scan: shape.scan(),
+            shape,
+            rows: PointRangeIterator::new(S::Point::rows(&range)),
+            x: range.start.x()..range.end.x(),
+            row: range.start,
+            spans: Vec::new(),
+            span: 0,
+            len: Cell::new(None),
+        }
+    }
+
+    /// Counts the cells left in the current row and in the rows after it.
+    fn count_len(&self) -> usize {
+        let mut scan = self.shape.scan();
+        let mut spans = Vec::new();
+        let rest = self
+            .rows
+            .clone()
+            .map(|row| {
+                Self::clipped_spans(&self.shape, &mut scan, &self.x, row, &mut spans);
+                spans.iter().map(|span| span.len()).sum::<usize>()
+            })
+            .sum::<usize>();
+        rest + self.spans[self.span..]
+            .iter()
+            .map(|span| span.len())
+            .sum::<usize>()
+    }
+
+    /// Replaces `spans` with the spans of `shape` in the row of `row` within `x`.
+    fn clipped_spans(
+        shape: &S,
+        scan: &mut S::Scan,
+        x: &ops::Range<i32>,
+        row: S::Point,
+        spans: &mut Vec<ops::Range<i32>>,
+    ) {
+        spans.clear();
+        shape.spans(scan, row, spans);
+        for span in spans.iter_mut() {
+            *span = span.start.max(x.start)..span.end.min(x.end);
+        }
+        spans.retain(|span| span.start < span.end);
+    }
+}
+
+impl<S: Shape> Iterator for ShapeIterator<S> {
+    type Item = S::Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(span) = self.spans.get_mut(self.span) {
+                let x = span.start;
+                span.start += 1;
+                if span.start == span.end {
+                    self.span += 1;
+                }
+                if let Some(len) = self.len.get_mut() {
+                    *len -= 1;
+                }
+                return Some(self.row.with_x(x));
+            }
+            self.row = self.rows.next()?;
+            Self::clipped_spans(
+                &self.shape,
+                &mut self.scan,
+                &self.x,
+                self.row,
+                &mut self.spans,
+            );
+            self.span = 0;
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len.get().unwrap_or_else(|| {
+            let len = self.count_len();
+            self.len.set(Some(len));
+            len
+        });
+        (len, Some(len))
+    }
+}
+
+impl<S: Shape> ExactSizeIterator for ShapeIterator<S> {}
+
+impl<S: Shape> iter::FusedIterator for ShapeIterator<S> {}
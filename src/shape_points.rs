@@ -0,0 +1,38 @@
+use crate::shape::Shape;
+use crate::shape_iterator::ShapeIterator;
+use crate::to_point_range::IntoPointRange;
+
+/// # Examples
+/// ```
+/// # use euclid::{Box2D, Point2D};
+/// # use euclid_ext::{Disk, Map2D, ShapePoints};
+/// enum Space {}
+/// let bounds = Box2D::new(Point2D::<i32, Space>::new(0, 0), Point2D::<i32, Space>::new(8, 8));
+/// let cells: Vec<Point2D<i32, Space>> = Disk::new(Point2D::new(0, 0), 2)
+///     .shape_points_within(bounds)
+///     .map(|p| p.map(|x| x * 2))
+///     .collect();
+/// assert_eq!(
+///     vec![
+///         Point2D::new(0, 0), Point2D::new(2, 0), Point2D::new(4, 0),
+///         Point2D::new(0, 2), Point2D::new(2, 2), Point2D::new(4, 2),
+///         Point2D::new(0, 4), Point2D::new(2, 4)
+///     ],
+///     cells);
+/// ```
+pub trait ShapePoints: Shape + Sized {
+    /// Iterates the cells of `self` in row-major order.
+    fn shape_points(self) -> ShapeIterator<Self> {
+        ShapeIterator::new(self)
+    }
+
+    /// Iterates the cells of `self` in `bounds` in row-major order.
+    fn shape_points_within<B: IntoPointRange<Point = Self::Point>>(
+        self,
+        bounds: B,
+    ) -> ShapeIterator<Self> {
+        ShapeIterator::with_bounds(self, bounds)
+    }
+}
+
+impl<S: Shape> ShapePoints for S {}
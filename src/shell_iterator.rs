@@ -0,0 +1,161 @@
+use crate::point_range_iterator::{
+    PointRange, PointRangeIterator, PointRangeIteratorItem, PointRangeIteratorPrimitive,
+};
+use crate::to_point_range::IntoPointRange;
+use std::{fmt, iter};
+
+pub trait ShellIteratorItem: PointRangeIteratorItem {
+    /// Returns the part of `range` more than `thickness` points away from its boundary.
+    fn interior(range: &PointRange<Self>, thickness: usize) -> PointRange<Self>;
+
+    /// Returns whether `range` contains `self`.
+    fn is_in(self, range: &PointRange<Self>) -> bool;
+}
+
+macro_rules! impl_shell_iterator_item {
+    ($point:ident) => {
+        impl<T: PointRangeIteratorPrimitive, U> ShellIteratorItem for euclid::$point<T, U> {
+            fn interior(range: &PointRange<Self>, thickness: usize) -> PointRange<Self> {
+                let (mut start, mut last) = (range.start.to_array(), range.start.to_array());
+                for (axis, &count) in range.counts().iter().enumerate() {
+                    if count.saturating_sub(thickness) <= thickness {
+                        return PointRange::new(range.start..range.start);
+                    }
+                    // The last point is kept instead of the end, which may not be representable.
+                    last[axis] = start[axis].offset(count - thickness - 1);
+                    start[axis] = start[axis].offset(thickness);
+                }
+                PointRange::inclusive(start.into()..=last.into())
+            }
+
+            fn is_in(self, range: &PointRange<Self>) -> bool {
+                let (start, end) = (range.start.to_array(), range.end.to_array());
+                self.to_array().iter().enumerate().all(|(axis, &value)| {
+                    start[axis] <= value
+                        && if range.end_inclusive {
+                            value <= end[axis]
+                        } else {
+                            value < end[axis]
+                        }
+                })
+            }
+        }
+    };
+}
+
+impl_shell_iterator_item!(Point2D);
+
+impl_shell_iterator_item!(Point3D);
+
+/// Iterates the points of a range within a given thickness of its boundary in row-major order,
+/// jumping over the rest a row at a time.
+#[derive(Clone)]
+pub struct ShellIterator<T: ShellIteratorItem> {
+    points: PointRangeIterator<T>,
+    interior: PointRange<T>,
+    interior_width: usize,
+    len: usize,
+}
+
+impl<T: ShellIteratorItem> fmt::Debug for ShellIterator<T>
+where
+    PointRange<T>: fmt::Debug,
+    PointRangeIterator<T>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ShellIterator")
+            .field("points", &self.points)
+            .field("interior", &self.interior)
+            .field("interior_width", &self.interior_width)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<T: ShellIteratorItem> PartialEq for ShellIterator<T>
+where
+    PointRange<T>: PartialEq,
+    PointRangeIterator<T>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.points == other.points
+            && self.interior == other.interior
+            && self.interior_width == other.interior_width
+            && self.len == other.len
+    }
+}
+
+impl<T: ShellIteratorItem> ShellIterator<T> {
+    /// # Examples
+    /// ```
+    /// # use euclid::Point2D;
+    /// # use euclid_ext::shell_iterator::ShellIterator;
+    /// enum Space {}
+    /// let mut i = ShellIterator::new(Point2D::<i64, Space>::new(0, 0)..Point2D::<i64, Space>::new(3, 3), 1);
+    /// assert_eq!(8, i.len());
+    /// assert_eq!(Some(Point2D::new(0, 0)), i.next());
+    /// assert_eq!(Some(Point2D::new(1, 0)), i.next());
+    /// assert_eq!(Some(Point2D::new(2, 0)), i.next());
+    /// assert_eq!(Some(Point2D::new(0, 1)), i.next());
+    /// assert_eq!(Some(Point2D::new(2, 1)), i.next());
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the range has more than `usize::MAX` points.
+    pub fn new<R: IntoPointRange<Point = T>>(range: R, thickness: usize) -> Self {
+        let range = range.into_point_range();
+        let interior = T::interior(&range, thickness);
+        let interior_width = T::row_len(&interior, T::Order::default());
+        let points = PointRangeIterator::from_point_range(range, T::Order::default());
+        let len = points.len() - T::len(&interior);
+        Self {
+            points,
+            interior,
+            interior_width,
+            len,
+        }
+    }
+
+    fn is_interior(&self, index: usize) -> bool {
+        self.points
+            .get(index)
+            .map_or(false, |point| point.is_in(&self.interior))
+    }
+}
+
+impl<T: ShellIteratorItem> Iterator for ShellIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        // Interior points come in whole rows, and the iterator never stops inside one.
+        if self.is_interior(0) {
+            self.points.nth(self.interior_width - 1);
+        }
+        self.len -= 1;
+        self.points.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T: ShellIteratorItem> DoubleEndedIterator for ShellIterator<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        if self.is_interior(self.points.len() - 1) {
+            self.points.nth_back(self.interior_width - 1);
+        }
+        self.len -= 1;
+        self.points.next_back()
+    }
+}
+
+impl<T: ShellIteratorItem> ExactSizeIterator for ShellIterator<T> {}
+
+impl<T: ShellIteratorItem> iter::FusedIterator for ShellIterator<T> {}
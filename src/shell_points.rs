@@ -0,0 +1,35 @@
+use crate::shell_iterator::{ShellIterator, ShellIteratorItem};
+use crate::to_point_range::IntoPointRange;
+
+/// # Examples
+/// ```
+/// # use euclid::{Box2D, Point2D};
+/// # use euclid_ext::ShellPoints;
+/// enum Space {}
+/// let range = Box2D::new(Point2D::<i64, Space>::new(0, 0), Point2D::<i64, Space>::new(4, 3));
+/// assert_eq!(
+///     vec![
+///         Point2D::new(0, 0), Point2D::new(1, 0), Point2D::new(2, 0), Point2D::new(3, 0),
+///         Point2D::new(0, 1), Point2D::new(3, 1),
+///         Point2D::new(0, 2), Point2D::new(1, 2), Point2D::new(2, 2), Point2D::new(3, 2)
+///     ],
+///     range.shell_points(1).collect::<Vec<_>>());
+/// ```
+pub trait ShellPoints {
+    type Iter: Iterator;
+
+    /// Iterates the points within `thickness` points of the boundary, that is the perimeter of
+    /// a 2D range or the shell of a 3D range, in row-major order.
+    fn shell_points(self, thickness: usize) -> Self::Iter;
+}
+
+impl<T: IntoPointRange> ShellPoints for T
+where
+    T::Point: ShellIteratorItem,
+{
+    type Iter = ShellIterator<T::Point>;
+
+    fn shell_points(self, thickness: usize) -> Self::Iter {
+        ShellIterator::new(self, thickness)
+    }
+}
@@ -0,0 +1,264 @@
+use crate::grid::{Grid2D, Grid3D};
+use std::collections::HashMap;
+use std::{fmt, ops};
+
+macro_rules! impl_sparse_grid {
+    ($sparse_grid:ident, $grid:ident, $box:ident, $point:ident, $size:ident, $dimensions:literal) => {
+        impl<T, U> $sparse_grid<T, U> {
+            /// Makes an empty grid of chunks of `chunk_size`, whose cells are set to `fill` when
+            /// their chunk is allocated.
+            ///
+            /// # Panics
+            /// Panics if `chunk_size` is not positive along every axis.
+            pub fn new(chunk_size: euclid::$size<i32, U>, fill: T) -> Self {
+                assert!(
+                    chunk_size.to_array().iter().all(|&length| length > 0),
+                    "chunk size must be positive"
+                );
+                Self {
+                    chunk_size,
+                    fill,
+                    chunks: HashMap::new(),
+                }
+            }
+
+            pub fn chunk_size(&self) -> euclid::$size<i32, U> {
+                self.chunk_size
+            }
+
+            /// Returns the value of the cells which have not been allocated.
+            pub fn fill(&self) -> &T {
+                &self.fill
+            }
+
+            /// Returns the bounds of the chunk containing `point`, clipped to the range of `i32`.
+            pub fn chunk_bounds(&self, point: euclid::$point<i32, U>) -> euclid::$box<i32, U> {
+                let (point, size) = (point.to_array(), self.chunk_size.to_array());
+                let (mut min, mut max) = ([0; $dimensions], [0; $dimensions]);
+                for axis in 0..$dimensions {
+                    let size = i64::from(size[axis]);
+                    let start = i64::from(point[axis]).div_euclid(size) * size;
+                    // The start of the first chunk and the end of the last may not be
+                    // representable.
+                    min[axis] = start.max(i64::from(i32::MIN)) as i32;
+                    max[axis] = (start + size).min(i64::from(i32::MAX)) as i32;
+                }
+                euclid::$box::new(min.into(), max.into())
+            }
+
+            /// Returns the number of allocated chunks.
+            pub fn chunk_count(&self) -> usize {
+                self.chunks.len()
+            }
+
+            /// Returns the number of cells in the allocated chunks.
+            pub fn len(&self) -> usize {
+                self.chunks.values().map(|chunk| chunk.len()).sum()
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.chunks.is_empty()
+            }
+
+            /// Returns the smallest box containing the allocated chunks.
+            pub fn bounds(&self) -> euclid::$box<i32, U> {
+                self.chunks
+                    .values()
+                    .map(|chunk| chunk.bounds())
+                    .reduce(|bounds, chunk| bounds.union(&chunk))
+                    .unwrap_or_else(euclid::$box::zero)
+            }
+
+            /// Returns the cell at `point`, or `None` if its chunk has not been allocated.
+            pub fn get(&self, point: euclid::$point<i32, U>) -> Option<&T> {
+                self.chunks.get(&self.chunk_bounds(point).min)?.get(point)
+            }
+
+            /// Returns the cell at `point`, or `None` if its chunk has not been allocated.
+            pub fn get_mut(&mut self, point: euclid::$point<i32, U>) -> Option<&mut T> {
+                let key = self.chunk_bounds(point).min;
+                self.chunks.get_mut(&key)?.get_mut(point)
+            }
+
+            /// Returns the cell at `point`, allocating its chunk if needed.
+            ///
+            /// # Panics
+            /// Panics if a coordinate of `point` is `i32::MAX`.
+            pub fn get_or_insert_mut(&mut self, point: euclid::$point<i32, U>) -> &mut T
+            where
+                T: Clone,
+            {
+                assert!(
+                    !point.to_array().contains(&i32::MAX),
+                    "cells at i32::MAX are outside every chunk"
+                );
+                let bounds = self.chunk_bounds(point);
+                let fill = &self.fill;
+                let chunk = self
+                    .chunks
+                    .entry(bounds.min)
+                    .or_insert_with(|| $grid::new(bounds, fill.clone()));
+                &mut chunk[point]
+            }
+
+            /// Sets the cell at `point`, allocating its chunk if needed.
+            ///
+            /// # Panics
+            /// Panics if a coordinate of `point` is `i32::MAX`.
+            pub fn set(&mut self, point: euclid::$point<i32, U>, value: T)
+            where
+                T: Clone,
+            {
+                *self.get_or_insert_mut(point) = value;
+            }
+
+            /// Removes the chunk containing `point` and returns it, if it has been allocated.
+            pub fn remove_chunk(&mut self, point: euclid::$point<i32, U>) -> Option<$grid<T, U>> {
+                let key = self.chunk_bounds(point).min;
+                self.chunks.remove(&key)
+            }
+
+            pub fn clear(&mut self) {
+                self.chunks.clear();
+            }
+
+            /// Iterates the allocated chunks in the order of `Points` over their positions.
+            pub fn chunks(&self) -> impl Iterator<Item = &$grid<T, U>> {
+                let mut chunks = self.chunks.values().collect::<Vec<_>>();
+                chunks.sort_unstable_by_key(|chunk| {
+                    let mut key = chunk.bounds().min.to_array();
+                    key.reverse();
+                    key
+                });
+                chunks.into_iter()
+            }
+
+            /// Iterates the allocated chunks mutably in the order of `chunks`.
+            pub fn chunks_mut(&mut self) -> impl Iterator<Item = &mut $grid<T, U>> {
+                let mut chunks = self.chunks.values_mut().collect::<Vec<_>>();
+                chunks.sort_unstable_by_key(|chunk| {
+                    let mut key = chunk.bounds().min.to_array();
+                    key.reverse();
+                    key
+                });
+                chunks.into_iter()
+            }
+
+            /// Iterates the points of the cells in the allocated chunks, chunk by chunk.
+            pub fn points(&self) -> impl Iterator<Item = euclid::$point<i32, U>> + '_ {
+                self.chunks().flat_map(|chunk| chunk.points())
+            }
+
+            /// Iterates the cells in the allocated chunks with their points, chunk by chunk.
+            pub fn iter(&self) -> impl Iterator<Item = (euclid::$point<i32, U>, &T)> {
+                self.chunks().flat_map(|chunk| chunk.iter())
+            }
+
+            /// Iterates the cells in the allocated chunks mutably with their points, chunk by
+            /// chunk.
+            pub fn iter_mut(&mut self) -> impl Iterator<Item = (euclid::$point<i32, U>, &mut T)> {
+                self.chunks_mut().flat_map(|chunk| chunk.iter_mut())
+            }
+        }
+
+        /// Returns the fill value for cells whose chunk has not been allocated.
+        impl<T, U> ops::Index<euclid::$point<i32, U>> for $sparse_grid<T, U> {
+            type Output = T;
+
+            fn index(&self, point: euclid::$point<i32, U>) -> &T {
+                self.get(point).unwrap_or(&self.fill)
+            }
+        }
+
+        /// Allocates the chunk of the cell if needed.
+        impl<T: Clone, U> ops::IndexMut<euclid::$point<i32, U>> for $sparse_grid<T, U> {
+            fn index_mut(&mut self, point: euclid::$point<i32, U>) -> &mut T {
+                self.get_or_insert_mut(point)
+            }
+        }
+
+        // Implemented by hand so that they are not bounded on the unit.
+        impl<T: Clone, U> Clone for $sparse_grid<T, U> {
+            fn clone(&self) -> Self {
+                Self {
+                    chunk_size: self.chunk_size,
+                    fill: self.fill.clone(),
+                    chunks: self.chunks.clone(),
+                }
+            }
+        }
+
+        impl<T: fmt::Debug, U> fmt::Debug for $sparse_grid<T, U> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct(stringify!($sparse_grid))
+                    .field("chunk_size", &self.chunk_size)
+                    .field("fill", &self.fill)
+                    .field("chunks", &self.chunks().collect::<Vec<_>>())
+                    .finish()
+            }
+        }
+
+        impl<T: PartialEq, U> PartialEq for $sparse_grid<T, U> {
+            fn eq(&self, other: &Self) -> bool {
+                self.chunk_size == other.chunk_size
+                    && self.fill == other.fill
+                    && self.chunks == other.chunks
+            }
+        }
+
+        impl<T: Eq, U> Eq for $sparse_grid<T, U> {}
+    };
+}
+
+/// A grid without bounds which stores its cells in chunks of a fixed size, allocating them when
+/// one of their cells is written.
+///
+/// # Examples
+/// ```
+/// # use euclid::{Box2D, Point2D, Size2D};
+/// # use euclid_ext::SparseGrid2D;
+/// enum Space {}
+/// let mut grid = SparseGrid2D::new(Size2D::<i32, Space>::new(4, 4), '.');
+/// grid[Point2D::new(-1, 2)] = '#';
+/// grid.set(Point2D::new(9, 0), '@');
+/// assert_eq!('.', grid[Point2D::new(100, 100)]);
+/// assert_eq!(None, grid.get(Point2D::new(100, 100)));
+/// assert_eq!(Some(&'#'), grid.get(Point2D::new(-1, 2)));
+/// assert_eq!(2, grid.chunk_count());
+/// assert_eq!(Box2D::new(Point2D::new(-4, 0), Point2D::new(12, 4)), grid.bounds());
+/// assert_eq!(
+///     vec![(Point2D::new(-1, 2), &'#'), (Point2D::new(9, 0), &'@')],
+///     grid.iter().filter(|&(_, &c)| c != '.').collect::<Vec<_>>());
+/// ```
+pub struct SparseGrid2D<T, U> {
+    chunk_size: euclid::Size2D<i32, U>,
+    fill: T,
+    /// The chunks keyed by their minimum points.
+    chunks: HashMap<euclid::Point2D<i32, U>, Grid2D<T, U>>,
+}
+
+impl_sparse_grid!(SparseGrid2D, Grid2D, Box2D, Point2D, Size2D, 2);
+
+/// A grid without bounds which stores its cells in chunks of a fixed size, allocating them when
+/// one of their cells is written.
+///
+/// # Examples
+/// ```
+/// # use euclid::{Point3D, Size3D};
+/// # use euclid_ext::SparseGrid3D;
+/// enum Space {}
+/// let mut grid = SparseGrid3D::new(Size3D::<i32, Space>::new(8, 8, 8), 0u8);
+/// grid[Point3D::new(0, 0, -1)] += 1;
+/// assert_eq!(512, grid.len());
+/// assert_eq!(
+///     Some(Point3D::new(0, 0, -1)),
+///     grid.iter().find(|&(_, &n)| n > 0).map(|(p, _)| p));
+/// ```
+pub struct SparseGrid3D<T, U> {
+    chunk_size: euclid::Size3D<i32, U>,
+    fill: T,
+    /// The chunks keyed by their minimum points.
+    chunks: HashMap<euclid::Point3D<i32, U>, Grid3D<T, U>>,
+}
+
+impl_sparse_grid!(SparseGrid3D, Grid3D, Box3D, Point3D, Size3D, 3);
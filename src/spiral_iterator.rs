@@ -0,0 +1,213 @@
+use crate::point_range_iterator::{offset_bounds, PointRangeIteratorPrimitive};
+use crate::to_point_range::IntoPointRange;
+use std::convert::TryFrom;
+use std::iter;
+
+// Far enough from any center that no point beyond it is representable.
+const UNBOUNDED: i128 = i128::MAX / 4;
+
+/// Returns the offset from `center` of the last value of `T` in `direction`, up to `UNBOUNDED`.
+fn reach<T: PointRangeIteratorPrimitive>(center: T, direction: i128) -> i128 {
+    let (mut low, mut high) = (0, UNBOUNDED);
+    while low < high {
+        let middle = low + (high - low + 1) / 2;
+        if center.checked_offset(middle * direction).is_some() {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+    low * direction
+}
+
+/// Returns the first offset and the direction of a side of a ring.
+fn side_start(radius: i128, side: usize) -> ([i128; 2], [i128; 2]) {
+    match side {
+        0 => ([radius, 1 - radius], [0, 1]),
+        1 => ([radius - 1, radius], [-1, 0]),
+        2 => ([-radius, radius - 1], [0, -1]),
+        _ => ([1 - radius, -radius], [1, 0]),
+    }
+}
+
+/// Iterates the points in square rings of growing radius around a center, starting with the
+/// center itself. Each ring starts next to the end of the previous one and runs counterclockwise
+/// when the y axis points up.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SpiralIterator<T> {
+    center: T,
+    min: [i128; 2],
+    max: [i128; 2],
+    last_radius: i128,
+    radius: i128,
+    side: usize,
+    position: i128,
+    end: i128,
+    remaining: Option<usize>,
+}
+
+impl<T> SpiralIterator<T> {
+    fn from_offset_bounds(
+        center: T,
+        min: [i128; 2],
+        max: [i128; 2],
+        max_radius: Option<usize>,
+    ) -> Self {
+        let bounds_radius = (0..2)
+            .map(|axis| min[axis].abs().max(max[axis].abs()))
+            .max()
+            .unwrap_or(0);
+        let last_radius = if (0..2).any(|axis| min[axis] > max[axis]) {
+            -1
+        } else {
+            max_radius.map_or(bounds_radius, |radius| bounds_radius.min(radius as i128))
+        };
+        let remaining = (0..2)
+            .try_fold(1, |len: i128, axis| {
+                let count = max[axis].min(last_radius) - min[axis].max(-last_radius) + 1;
+                len.checked_mul(count.max(0))
+            })
+            .and_then(|len| usize::try_from(len).ok());
+        let contains_center = (0..2).all(|axis| min[axis] <= 0 && 0 <= max[axis]);
+        Self {
+            center,
+            min,
+            max,
+            last_radius,
+            radius: 0,
+            side: 3,
+            position: 0,
+            end: contains_center as i128,
+            remaining,
+        }
+    }
+
+    fn next_side(&mut self) {
+        self.side += 1;
+        if self.side == 4 {
+            self.side = 0;
+            self.radius += 1;
+        }
+        let (start, direction) = side_start(self.radius, self.side);
+        let (mut first, mut last) = (0, self.radius * 2 - 1);
+        for axis in 0..2 {
+            let (min, max) = (self.min[axis] - start[axis], self.max[axis] - start[axis]);
+            match direction[axis] {
+                0 if min > 0 || max < 0 => last = -1,
+                1 => {
+                    first = first.max(min);
+                    last = last.min(max);
+                }
+                -1 => {
+                    first = first.max(-max);
+                    last = last.min(-min);
+                }
+                _ => {}
+            }
+        }
+        self.position = first;
+        self.end = first.max(last + 1);
+    }
+}
+
+impl<T: PointRangeIteratorPrimitive, U> SpiralIterator<euclid::Point2D<T, U>> {
+    /// Iterates the rings up to `max_radius`, or until they cover the range of `T` if it is
+    /// `None`, skipping the points out of the range of `T`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use euclid::Point2D;
+    /// # use euclid_ext::spiral_iterator::SpiralIterator;
+    /// enum Space {}
+    /// let mut i = SpiralIterator::new(Point2D::<i64, Space>::new(10, 20), Some(1));
+    /// assert_eq!((9, Some(9)), i.size_hint());
+    /// assert_eq!(Some(Point2D::new(10, 20)), i.next());
+    /// assert_eq!(Some(Point2D::new(11, 20)), i.next());
+    /// assert_eq!(Some(Point2D::new(11, 21)), i.next());
+    /// assert_eq!(Some(Point2D::new(10, 21)), i.next());
+    /// assert_eq!(Some(Point2D::new(9, 21)), i.next());
+    /// ```
+    pub fn new(center: euclid::Point2D<T, U>, max_radius: Option<usize>) -> Self {
+        let (min, max) = (
+            [reach(center.x, -1), reach(center.y, -1)],
+            [reach(center.x, 1), reach(center.y, 1)],
+        );
+        Self::from_offset_bounds(center, min, max, max_radius)
+    }
+
+    /// Iterates the rings up to `max_radius`, skipping the points outside `bounds`.
+    /// The iteration ends once the rings have covered `bounds`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use euclid::Point2D;
+    /// # use euclid_ext::spiral_iterator::SpiralIterator;
+    /// enum Space {}
+    /// let bounds = Point2D::<u32, Space>::new(0, 0)..Point2D::<u32, Space>::new(2, 2);
+    /// let mut i = SpiralIterator::with_bounds(Point2D::new(0, 0), bounds, None);
+    /// assert_eq!(Some(Point2D::new(0, 0)), i.next());
+    /// assert_eq!(Some(Point2D::new(1, 0)), i.next());
+    /// assert_eq!(Some(Point2D::new(1, 1)), i.next());
+    /// assert_eq!(Some(Point2D::new(0, 1)), i.next());
+    /// assert_eq!(None, i.next());
+    /// ```
+    pub fn with_bounds<B: IntoPointRange<Point = euclid::Point2D<T, U>>>(
+        center: euclid::Point2D<T, U>,
+        bounds: B,
+        max_radius: Option<usize>,
+    ) -> Self {
+        let bounds = bounds.into_point_range();
+        let (start, end, inclusive) = (bounds.start, bounds.end, bounds.end_inclusive);
+        let x = offset_bounds(center.x, start.x, end.x, inclusive);
+        let y = offset_bounds(center.y, start.y, end.y, inclusive);
+        Self::from_offset_bounds(center, [x.0, y.0], [x.1, y.1], max_radius)
+    }
+}
+
+impl<T: PointRangeIteratorPrimitive, U> Iterator for SpiralIterator<euclid::Point2D<T, U>> {
+    type Item = euclid::Point2D<T, U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.position >= self.end {
+            if self.side == 3 && self.radius >= self.last_radius {
+                return None;
+            }
+            self.next_side();
+        }
+        let offset = if self.radius == 0 {
+            [0, 0]
+        } else {
+            let (start, direction) = side_start(self.radius, self.side);
+            [
+                start[0] + direction[0] * self.position,
+                start[1] + direction[1] * self.position,
+            ]
+        };
+        self.position += 1;
+        if let Some(remaining) = &mut self.remaining {
+            *remaining -= 1;
+        }
+        // The bounds keep every offset within the range of `T`.
+        let point = |value: T, offset| {
+            value
+                .checked_offset(offset)
+                .expect("spiral point is out of the range of the coordinate type")
+        };
+        Some(euclid::Point2D::new(
+            point(self.center.x, offset[0]),
+            point(self.center.y, offset[1]),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl<T: PointRangeIteratorPrimitive, U> iter::FusedIterator
+    for SpiralIterator<euclid::Point2D<T, U>>
+{
+}
@@ -0,0 +1,45 @@
+use crate::point_range_iterator::PointRangeIteratorPrimitive;
+use crate::spiral_iterator::SpiralIterator;
+use crate::to_point_range::IntoPointRange;
+
+/// # Examples
+/// ```
+/// # use euclid::{Box2D, Point2D};
+/// # use euclid_ext::SpiralPoints;
+/// enum Space {}
+/// let bounds = Box2D::new(Point2D::<i64, Space>::new(0, 0), Point2D::<i64, Space>::new(10, 10));
+/// let free = |p: &Point2D<i64, Space>| p.x + p.y > 12;
+/// assert_eq!(
+///     Some(Point2D::new(7, 6)),
+///     Point2D::new(6, 5).spiral_points_within(bounds, None).find(free));
+/// ```
+pub trait SpiralPoints: Sized {
+    type Iter: Iterator<Item = Self>;
+
+    /// Iterates the points in square rings of growing radius around `self`, up to `max_radius`.
+    fn spiral_points(self, max_radius: Option<usize>) -> Self::Iter;
+
+    /// Iterates the points in square rings of growing radius around `self`, up to `max_radius`,
+    /// skipping the points outside `bounds`.
+    fn spiral_points_within<B: IntoPointRange<Point = Self>>(
+        self,
+        bounds: B,
+        max_radius: Option<usize>,
+    ) -> Self::Iter;
+}
+
+impl<T: PointRangeIteratorPrimitive, U> SpiralPoints for euclid::Point2D<T, U> {
+    type Iter = SpiralIterator<Self>;
+
+    fn spiral_points(self, max_radius: Option<usize>) -> Self::Iter {
+        SpiralIterator::new(self, max_radius)
+    }
+
+    fn spiral_points_within<B: IntoPointRange<Point = Self>>(
+        self,
+        bounds: B,
+        max_radius: Option<usize>,
+    ) -> Self::Iter {
+        SpiralIterator::with_bounds(self, bounds, max_radius)
+    }
+}
@@ -126,6 +126,26 @@ impl<T: Copy, U> Structure3D for euclid::Point3D<T, U> {
     }
 }
 
+impl<T: Copy, U> Structure3D for euclid::Size3D<T, U> {
+    type Item = euclid::Length<T, U>;
+
+    fn from_x_y_z(x: Self::Item, y: Self::Item, z: Self::Item) -> Self {
+        Self::from_lengths(x, y, z)
+    }
+
+    fn x(&self) -> Self::Item {
+        Self::Item::new(self.width)
+    }
+
+    fn y(&self) -> Self::Item {
+        Self::Item::new(self.height)
+    }
+
+    fn z(&self) -> Self::Item {
+        Self::Item::new(self.depth)
+    }
+}
+
 impl<T: Copy, U> Structure3D for euclid::Vector3D<T, U> {
     type Item = euclid::Length<T, U>;
 
@@ -167,4 +187,4 @@ impl<T: Copy, U> Structure3D for euclid::Box3D<T, U> {
     fn z(&self) -> Self::Item {
         (self.min.z(), self.max.z())
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,262 @@
+use crate::point_range_iterator::{
+    PointRange, PointRangeIterator, PointRangeIteratorItem, PointRangeIteratorPrimitive,
+};
+use crate::to_point_range::IntoPointRange;
+use std::{fmt, iter, ops};
+
+// Compares distances instead of adding to or subtracting from the bounds directly,
+// so that tiles at the edges of unsigned or bounded ranges do not overflow.
+fn tile_bounds<T>(range: (T, T, bool), origin: T, size: T, halo: T) -> (T, T)
+where
+    T: PointRangeIteratorPrimitive + ops::Sub<Output = T>,
+{
+    let (start, end, end_inclusive) = range;
+    let min = if origin - start < halo {
+        start
+    } else {
+        origin - halo
+    };
+    let max = if end_inclusive {
+        // The box ends after the last point, which is `end` itself for integers.
+        let last = start.offset(T::inclusive_steps_by(start, end, T::one()) - 1);
+        if last - origin >= size && last - origin - size >= halo {
+            origin + size + halo
+        } else {
+            last.checked_offset(1)
+                .expect("tile ends past the maximum of the coordinate type")
+        }
+    } else if end - origin >= size && end - origin - size >= halo {
+        origin + size + halo
+    } else {
+        end
+    };
+    (min, max)
+}
+
+pub trait TileIteratorItem: PointRangeIteratorItem {
+    type Size: Copy;
+
+    type Tile: IntoPointRange<Point = Self>;
+
+    fn zero_size() -> Self::Size;
+
+    /// Returns the step between the origins of tiles of `size`.
+    fn tile_step(size: Self::Size) -> Self::Step;
+
+    /// Returns the tile of `size` at `origin` grown by `halo` on every side and clipped to `range`.
+    fn tile(
+        range: &PointRange<Self>,
+        origin: Self,
+        size: Self::Size,
+        halo: Self::Size,
+    ) -> Self::Tile;
+}
+
+impl<T, U> TileIteratorItem for euclid::Point2D<T, U>
+where
+    T: PointRangeIteratorPrimitive + ops::Sub<Output = T> + euclid::num::Zero,
+{
+    type Size = euclid::Size2D<T, U>;
+
+    type Tile = euclid::Box2D<T, U>;
+
+    fn zero_size() -> Self::Size {
+        Self::Size::zero()
+    }
+
+    fn tile_step(size: Self::Size) -> Self::Step {
+        size.to_vector()
+    }
+
+    fn tile(
+        range: &PointRange<Self>,
+        origin: Self,
+        size: Self::Size,
+        halo: Self::Size,
+    ) -> Self::Tile {
+        let (start, end, inclusive) = (range.start, range.end, range.end_inclusive);
+        let x = tile_bounds(
+            (start.x, end.x, inclusive),
+            origin.x,
+            size.width,
+            halo.width,
+        );
+        let y = tile_bounds(
+            (start.y, end.y, inclusive),
+            origin.y,
+            size.height,
+            halo.height,
+        );
+        Self::Tile::new(Self::new(x.0, y.0), Self::new(x.1, y.1))
+    }
+}
+
+impl<T, U> TileIteratorItem for euclid::Point3D<T, U>
+where
+    T: PointRangeIteratorPrimitive + ops::Sub<Output = T> + euclid::num::Zero,
+{
+    type Size = euclid::Size3D<T, U>;
+
+    type Tile = euclid::Box3D<T, U>;
+
+    fn zero_size() -> Self::Size {
+        Self::Size::zero()
+    }
+
+    fn tile_step(size: Self::Size) -> Self::Step {
+        size.to_vector()
+    }
+
+    fn tile(
+        range: &PointRange<Self>,
+        origin: Self,
+        size: Self::Size,
+        halo: Self::Size,
+    ) -> Self::Tile {
+        let (start, end, inclusive) = (range.start, range.end, range.end_inclusive);
+        let x = tile_bounds(
+            (start.x, end.x, inclusive),
+            origin.x,
+            size.width,
+            halo.width,
+        );
+        let y = tile_bounds(
+            (start.y, end.y, inclusive),
+            origin.y,
+            size.height,
+            halo.height,
+        );
+        let z = tile_bounds(
+            (start.z, end.z, inclusive),
+            origin.z,
+            size.depth,
+            halo.depth,
+        );
+        Self::Tile::new(Self::new(x.0, y.0, z.0), Self::new(x.1, y.1, z.1))
+    }
+}
+
+/// Iterates the tiles covering a range in row-major order.
+/// Tiles on the far edges are clipped to the range.
+#[derive(Clone)]
+pub struct TileIterator<T: TileIteratorItem> {
+    range: PointRange<T>,
+    origins: PointRangeIterator<T>,
+    size: T::Size,
+    halo: T::Size,
+}
+
+impl<T: TileIteratorItem> fmt::Debug for TileIterator<T>
+where
+    PointRange<T>: fmt::Debug,
+    PointRangeIterator<T>: fmt::Debug,
+    T::Size: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TileIterator")
+            .field("range", &self.range)
+            .field("origins", &self.origins)
+            .field("size", &self.size)
+            .field("halo", &self.halo)
+            .finish()
+    }
+}
+
+impl<T: TileIteratorItem> PartialEq for TileIterator<T>
+where
+    PointRange<T>: PartialEq,
+    PointRangeIterator<T>: PartialEq,
+    T::Size: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.range == other.range
+            && self.origins == other.origins
+            && self.size == other.size
+            && self.halo == other.halo
+    }
+}
+
+impl<T: TileIteratorItem> TileIterator<T> {
+    /// # Panics
+    /// Panics if `size` is not positive along every axis, or if the range is inclusive and
+    /// a tile ends at the maximum of the coordinate type, where its box is not representable.
+    pub fn new<R: IntoPointRange<Point = T>>(range: R, size: T::Size) -> Self {
+        Self::with_halo(range, size, T::zero_size())
+    }
+
+    /// Makes an iterator of tiles which overlap their neighbors by `halo` on every side.
+    ///
+    /// # Examples
+    /// ```
+    /// # use euclid::{Box2D, Point2D, Size2D};
+    /// # use euclid_ext::tile_iterator::TileIterator;
+    /// enum Space {}
+    /// let mut i = TileIterator::with_halo(Point2D::<i64, Space>::new(0, 0)..Point2D::<i64, Space>::new(10, 4), Size2D::new(4, 4), Size2D::new(1, 1));
+    /// assert_eq!(Some(Box2D::new(Point2D::new(0, 0), Point2D::new(5, 4))), i.next());
+    /// assert_eq!(Some(Box2D::new(Point2D::new(3, 0), Point2D::new(9, 4))), i.next());
+    /// assert_eq!(Some(Box2D::new(Point2D::new(7, 0), Point2D::new(10, 4))), i.next());
+    /// assert_eq!(None, i.next());
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `size` is not positive along every axis, or if the range is inclusive and
+    /// a tile ends at the maximum of the coordinate type, where its box is not representable.
+    pub fn with_halo<R: IntoPointRange<Point = T>>(range: R, size: T::Size, halo: T::Size) -> Self {
+        let range = range.into_point_range();
+        let origins = PointRangeIterator::from_point_range(
+            range.clone().step_by(T::tile_step(size)),
+            T::Order::default(),
+        );
+        Self {
+            range,
+            origins,
+            size,
+            halo,
+        }
+    }
+
+    fn tile(&self, origin: T) -> T::Tile {
+        T::tile(&self.range, origin, self.size, self.halo)
+    }
+}
+
+/// # Examples
+/// ```
+/// # use euclid::{Box2D, Point2D, Size2D};
+/// # use euclid_ext::tile_iterator::TileIterator;
+/// enum Space {}
+/// let mut i = TileIterator::new(Point2D::<i64, Space>::new(0, 0)..Point2D::<i64, Space>::new(5, 3), Size2D::new(3, 2));
+/// assert_eq!(Some(Box2D::new(Point2D::new(0, 0), Point2D::new(3, 2))), i.next());
+/// assert_eq!(Some(Box2D::new(Point2D::new(3, 0), Point2D::new(5, 2))), i.next());
+/// assert_eq!(Some(Box2D::new(Point2D::new(0, 2), Point2D::new(3, 3))), i.next());
+/// assert_eq!(Some(Box2D::new(Point2D::new(3, 2), Point2D::new(5, 3))), i.next());
+/// assert_eq!(None, i.next());
+/// ```
+impl<T: TileIteratorItem> Iterator for TileIterator<T> {
+    type Item = T::Tile;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let origin = self.origins.next()?;
+        Some(self.tile(origin))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.origins.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let origin = self.origins.nth(n)?;
+        Some(self.tile(origin))
+    }
+}
+
+impl<T: TileIteratorItem> DoubleEndedIterator for TileIterator<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let origin = self.origins.next_back()?;
+        Some(self.tile(origin))
+    }
+}
+
+impl<T: TileIteratorItem> ExactSizeIterator for TileIterator<T> {}
+
+impl<T: TileIteratorItem> iter::FusedIterator for TileIterator<T> {}
@@ -0,0 +1,64 @@
+use crate::point_range_iterator::PointRangeIterator;
+use crate::points::Points;
+use crate::tile_iterator::{TileIterator, TileIteratorItem};
+use crate::to_point_range::IntoPointRange;
+use std::iter;
+
+fn tile_points<T: TileIteratorItem>(tile: T::Tile) -> PointRangeIterator<T> {
+    tile.points()
+}
+
+/// # Examples
+/// ```
+/// # use euclid::{Box2D, Point2D, Size2D};
+/// # use euclid_ext::Tiles;
+/// enum Space {}
+/// let range = Box2D::new(Point2D::<i64, Space>::new(0, 0), Point2D::<i64, Space>::new(3, 2));
+/// assert_eq!(
+///     vec![
+///         Point2D::new(0, 0), Point2D::new(1, 0), Point2D::new(0, 1), Point2D::new(1, 1),
+///         Point2D::new(2, 0), Point2D::new(2, 1)
+///     ],
+///     range.points_by_tile(Size2D::new(2, 2)).collect::<Vec<_>>());
+/// ```
+pub trait Tiles {
+    type Size;
+
+    type Iter: Iterator;
+
+    type PointsIter: Iterator;
+
+    fn tiles(self, size: Self::Size) -> Self::Iter;
+
+    fn tiles_with_halo(self, size: Self::Size, halo: Self::Size) -> Self::Iter;
+
+    /// Iterates the points tile by tile, visiting the points in each tile in row-major order.
+    fn points_by_tile(self, size: Self::Size) -> Self::PointsIter;
+}
+
+impl<T: IntoPointRange> Tiles for T
+where
+    T::Point: TileIteratorItem,
+{
+    type Size = <T::Point as TileIteratorItem>::Size;
+
+    type Iter = TileIterator<T::Point>;
+
+    type PointsIter = iter::FlatMap<
+        TileIterator<T::Point>,
+        PointRangeIterator<T::Point>,
+        fn(<T::Point as TileIteratorItem>::Tile) -> PointRangeIterator<T::Point>,
+    >;
+
+    fn tiles(self, size: Self::Size) -> Self::Iter {
+        TileIterator::new(self, size)
+    }
+
+    fn tiles_with_halo(self, size: Self::Size, halo: Self::Size) -> Self::Iter {
+        TileIterator::with_halo(self, size, halo)
+    }
+
+    fn points_by_tile(self, size: Self::Size) -> Self::PointsIter {
+        self.tiles(size).flat_map(tile_points::<T::Point>)
+    }
+}
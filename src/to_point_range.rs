@@ -1,3 +1,4 @@
+use crate::point_range_iterator::{PointRange, PointRangeIteratorItem};
 use std::ops;
 
 pub trait ToPointRange {
@@ -16,8 +17,7 @@ impl<T, U> ToPointRange for ops::Range<euclid::Point2D<T, U>> {
 
 impl<T, U> ToPointRange for euclid::Rect<T, U>
 where
-    euclid::Point2D<T, U>:
-        Clone + ops::Add<euclid::Size2D<T, U>, Output = euclid::Point2D<T, U>>,
+    euclid::Point2D<T, U>: Clone + ops::Add<euclid::Size2D<T, U>, Output = euclid::Point2D<T, U>>,
 {
     type Point = euclid::Point2D<T, U>;
 
@@ -49,4 +49,46 @@ impl<T, U> ToPointRange for euclid::Box3D<T, U> {
     fn to_point_range(self) -> ops::Range<Self::Point> {
         self.min..self.max
     }
-}
\ No newline at end of file
+}
+
+/// Conversion into a `PointRange`, which unlike `ops::Range` can also include its end.
+///
+/// It is implemented for every `ToPointRange` whose points can be iterated, and for inclusive
+/// ranges of points.
+pub trait IntoPointRange {
+    type Point: PointRangeIteratorItem;
+
+    fn into_point_range(self) -> PointRange<Self::Point>;
+}
+
+impl<R: ToPointRange> IntoPointRange for R
+where
+    R::Point: PointRangeIteratorItem,
+{
+    type Point = R::Point;
+
+    fn into_point_range(self) -> PointRange<Self::Point> {
+        PointRange::new(self.to_point_range())
+    }
+}
+
+/// # Examples
+/// ```
+/// # use euclid::Point2D;
+/// # use euclid_ext::Points;
+/// enum Space {}
+/// let range = Point2D::<i32, Space>::new(i32::MAX - 1, 20)..=Point2D::<i32, Space>::new(i32::MAX, 21);
+/// assert_eq!(
+///     vec![
+///         Point2D::new(i32::MAX - 1, 20), Point2D::new(i32::MAX, 20),
+///         Point2D::new(i32::MAX - 1, 21), Point2D::new(i32::MAX, 21)
+///     ],
+///     range.points().collect::<Vec<_>>());
+/// ```
+impl<T: PointRangeIteratorItem> IntoPointRange for ops::RangeInclusive<T> {
+    type Point = T;
+
+    fn into_point_range(self) -> PointRange<Self::Point> {
+        PointRange::inclusive(self)
+    }
+}
@@ -0,0 +1,81 @@
+use euclid::{Box2D, Box3D, Point2D, Point3D};
+use euclid_ext::{CellCoverage, Points};
+
+enum Space {}
+
+#[test]
+fn cells_of_box_with_integer_ends() {
+    let b = Box2D::<f32, Space>::new(Point2D::new(-2.0, 1.0), Point2D::new(3.0, 4.0));
+    let cells = Box2D::new(Point2D::new(-2, 1), Point2D::new(3, 4));
+    assert_eq!(cells, b.touched_cells());
+    assert_eq!(cells, b.covered_cells());
+    assert_eq!(15, b.touched_points().len());
+}
+
+#[test]
+fn cells_of_box_with_fractional_ends() {
+    let b = Box2D::<f64, Space>::new(Point2D::new(-2.5, 0.25), Point2D::new(1.75, 3.0));
+    assert_eq!(
+        Box2D::new(Point2D::new(-3, 0), Point2D::new(2, 3)),
+        b.touched_cells()
+    );
+    assert_eq!(
+        Box2D::new(Point2D::new(-2, 1), Point2D::new(1, 3)),
+        b.covered_cells()
+    );
+    assert_eq!(
+        b.covered_cells().points().collect::<Vec<_>>(),
+        b.covered_points().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn cells_of_box_inside_one_cell() {
+    let b = Box2D::<f32, Space>::new(Point2D::new(4.25, -0.75), Point2D::new(4.5, -0.5));
+    assert_eq!(
+        vec![Point2D::new(4, -1)],
+        b.touched_points().collect::<Vec<_>>()
+    );
+    assert!(b.covered_cells().is_empty_or_negative());
+    assert_eq!(0, b.covered_points().len());
+}
+
+#[test]
+fn cells_of_empty_box() {
+    let flat = Box2D::<f32, Space>::new(Point2D::new(1.5, 0.0), Point2D::new(1.5, 2.0));
+    assert_eq!(0, flat.touched_points().len());
+    let negative = Box2D::<f32, Space>::new(Point2D::new(2.0, 0.0), Point2D::new(1.0, 2.0));
+    assert_eq!(0, negative.touched_points().len());
+    let nan = Box2D::<f32, Space>::new(Point2D::new(f32::NAN, 0.0), Point2D::new(1.0, 2.0));
+    assert_eq!(0, nan.touched_points().len());
+    assert_eq!(0, nan.covered_points().len());
+}
+
+#[test]
+fn cells_of_box_3d() {
+    let b = Box3D::<f64, Space>::new(Point3D::new(0.5, -1.0, 2.0), Point3D::new(2.0, 0.5, 4.5));
+    assert_eq!(
+        Box3D::new(Point3D::new(0, -1, 2), Point3D::new(2, 1, 5)),
+        b.touched_cells()
+    );
+    assert_eq!(
+        Box3D::new(Point3D::new(1, -1, 2), Point3D::new(2, 0, 4)),
+        b.covered_cells()
+    );
+    assert_eq!(
+        vec![Point3D::new(1, -1, 2), Point3D::new(1, -1, 3)],
+        b.covered_points().collect::<Vec<_>>()
+    );
+    let f32_box =
+        Box3D::<f32, Space>::new(Point3D::new(0.5, -1.0, 2.0), Point3D::new(2.0, 0.5, 4.5));
+    assert_eq!(b.touched_cells(), f32_box.touched_cells());
+}
+
+#[test]
+fn cells_of_huge_box_saturate() {
+    let b = Box2D::<f64, Space>::new(Point2D::new(-1e20, 0.0), Point2D::new(1e20, 1.0));
+    assert_eq!(
+        Box2D::new(Point2D::new(i32::MIN, 0), Point2D::new(i32::MAX, 1)),
+        b.touched_cells()
+    );
+}
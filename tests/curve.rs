@@ -0,0 +1,164 @@
+use euclid::{Box2D, Box3D};
+use euclid_ext::curve::{
+    hilbert_decode_2d, hilbert_decode_3d, hilbert_encode_2d, hilbert_encode_3d, morton_decode_2d,
+    morton_decode_3d, morton_encode_2d, morton_encode_3d,
+};
+use euclid_ext::{CurvePoints, Points};
+
+enum Space {}
+
+type Point2D<T> = euclid::Point2D<T, Space>;
+
+type Point3D<T> = euclid::Point3D<T, Space>;
+
+fn is_adjacent_2d(a: Point2D<u32>, b: Point2D<u32>) -> bool {
+    (a.x as i64 - b.x as i64).abs() + (a.y as i64 - b.y as i64).abs() == 1
+}
+
+fn is_adjacent_3d(a: Point3D<u32>, b: Point3D<u32>) -> bool {
+    (a.x as i64 - b.x as i64).abs()
+        + (a.y as i64 - b.y as i64).abs()
+        + (a.z as i64 - b.z as i64).abs()
+        == 1
+}
+
+#[test]
+fn morton_2d() {
+    assert_eq!(0, morton_encode_2d(Point2D::new(0, 0)));
+    assert_eq!(1, morton_encode_2d(Point2D::new(1, 0)));
+    assert_eq!(2, morton_encode_2d(Point2D::new(0, 1)));
+    assert_eq!(0b111010, morton_encode_2d(Point2D::new(0b100, 0b111)));
+    assert_eq!(u64::MAX, morton_encode_2d(Point2D::new(u32::MAX, u32::MAX)));
+    for &point in &[
+        Point2D::new(0, 0),
+        Point2D::new(12345, 67890),
+        Point2D::new(u32::MAX, 7),
+    ] {
+        assert_eq!(point, morton_decode_2d(morton_encode_2d(point)));
+    }
+}
+
+#[test]
+fn morton_3d() {
+    assert_eq!(1, morton_encode_3d(Point3D::new(1, 0, 0)));
+    assert_eq!(2, morton_encode_3d(Point3D::new(0, 1, 0)));
+    assert_eq!(4, morton_encode_3d(Point3D::new(0, 0, 1)));
+    assert_eq!(
+        (1 << 96) - 1,
+        morton_encode_3d(Point3D::new(u32::MAX, u32::MAX, u32::MAX))
+    );
+    for &point in &[
+        Point3D::new(0, 0, 0),
+        Point3D::new(12345, 67890, 13579),
+        Point3D::new(u32::MAX, 7, 1 << 31),
+    ] {
+        assert_eq!(point, morton_decode_3d(morton_encode_3d(point)));
+    }
+}
+
+#[test]
+fn hilbert_2d_visits_adjacent_cells() {
+    for bits in 0..6 {
+        let points = (0..1 << (2 * bits))
+            .map(|index| hilbert_decode_2d::<Space>(index, bits))
+            .collect::<Vec<_>>();
+        for (index, &point) in points.iter().enumerate() {
+            assert_eq!(index as u64, hilbert_encode_2d(point, bits));
+        }
+        assert!(points.windows(2).all(|w| is_adjacent_2d(w[0], w[1])));
+    }
+    let point = Point2D::new(u32::MAX, 12345);
+    assert_eq!(point, hilbert_decode_2d(hilbert_encode_2d(point, 32), 32));
+}
+
+#[test]
+fn hilbert_3d_visits_adjacent_cells() {
+    for bits in 0..4 {
+        let points = (0..1 << (3 * bits))
+            .map(|index| hilbert_decode_3d::<Space>(index, bits))
+            .collect::<Vec<_>>();
+        for (index, &point) in points.iter().enumerate() {
+            assert_eq!(index as u128, hilbert_encode_3d(point, bits));
+        }
+        assert!(points.windows(2).all(|w| is_adjacent_3d(w[0], w[1])));
+    }
+    let point = Point3D::new(u32::MAX, 12345, 1 << 31);
+    assert_eq!(point, hilbert_decode_3d(hilbert_encode_3d(point, 32), 32));
+}
+
+#[test]
+#[should_panic(expected = "bits must be at most 32")]
+fn hilbert_with_too_many_bits() {
+    hilbert_encode_2d(Point2D::new(0, 0), 33);
+}
+
+#[test]
+fn morton_points_in_box2d() {
+    let range = Box2D::new(Point2D::new(-3, 5), Point2D::new(4, 10));
+    let mut expected = range.points().collect::<Vec<_>>();
+    expected.sort_by_key(|p| morton_encode_2d(Point2D::new((p.x + 3) as u32, (p.y - 5) as u32)));
+    let points = range.morton_points();
+    assert_eq!(35, points.len());
+    assert_eq!(expected, points.collect::<Vec<_>>());
+}
+
+#[test]
+fn hilbert_points_in_box2d() {
+    let range = Box2D::new(Point2D::new(-3, 5), Point2D::new(10, 8));
+    let mut expected = range.points().collect::<Vec<_>>();
+    expected
+        .sort_by_key(|p| hilbert_encode_2d(Point2D::new((p.x + 3) as u32, (p.y - 5) as u32), 4));
+    assert_eq!(expected, range.hilbert_points().collect::<Vec<_>>());
+}
+
+#[test]
+fn hilbert_points_in_box3d() {
+    let range = Box3D::new(Point3D::new(1, 2, 3), Point3D::new(6, 5, 4));
+    let mut expected = range.points().collect::<Vec<_>>();
+    expected.sort_by_key(|p| {
+        hilbert_encode_3d(
+            Point3D::new((p.x - 1) as u32, (p.y - 2) as u32, (p.z - 3) as u32),
+            3,
+        )
+    });
+    let points = range.hilbert_points();
+    assert_eq!(15, points.len());
+    assert_eq!(expected, points.collect::<Vec<_>>());
+}
+
+#[test]
+fn morton_points_in_box3d() {
+    let range = Box3D::new(Point3D::new(1, 2, 3), Point3D::new(4, 9, 5));
+    let mut expected = range.points().collect::<Vec<_>>();
+    expected.sort_by_key(|p| {
+        morton_encode_3d(Point3D::new(
+            (p.x - 1) as u32,
+            (p.y - 2) as u32,
+            (p.z - 3) as u32,
+        ))
+    });
+    assert_eq!(expected, range.morton_points().collect::<Vec<_>>());
+}
+
+#[test]
+fn curve_points_in_thin_box() {
+    let range = Box2D::new(Point2D::new(0, 0), Point2D::new(100_000, 1));
+    assert_eq!(100_000, range.hilbert_points().count());
+    assert!(range.morton_points().eq(range.points()));
+}
+
+#[test]
+fn curve_points_in_empty_box() {
+    let range = Box2D::new(Point2D::new(5, 5), Point2D::new(5, 9));
+    assert_eq!(None, range.morton_points().next());
+    assert_eq!(None, range.hilbert_points().next());
+}
+
+#[test]
+fn curve_points_in_inclusive_range() {
+    let range = Point2D::new(u8::MAX - 2, 0)..=Point2D::new(u8::MAX, 1);
+    let mut points = range.clone().morton_points().collect::<Vec<_>>();
+    assert_eq!(6, points.len());
+    points.sort_by_key(|p| (p.y, p.x));
+    assert_eq!(range.points().collect::<Vec<_>>(), points);
+}
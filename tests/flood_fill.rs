@@ -0,0 +1,139 @@
+use euclid::{Box2D, Box3D};
+use euclid_ext::{FloodFill, Neighborhood2D, Neighborhood3D, Neighbors, Points};
+use std::cell::Cell;
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+
+enum Space {}
+
+type Point2D<T> = euclid::Point2D<T, Space>;
+type Point3D<T> = euclid::Point3D<T, Space>;
+
+fn is_open(x: i32, y: i32, z: i32) -> bool {
+    let hash =
+        (x.wrapping_mul(73_856_093) ^ y.wrapping_mul(19_349_663) ^ z.wrapping_mul(83_492_791))
+            .wrapping_mul(2_654_435_761u32 as i32);
+    (hash >> 16) & 3 != 0
+}
+
+fn breadth_first<T: Copy + Eq + Hash, I: Iterator<Item = T>>(
+    seed: T,
+    open: impl Fn(T) -> bool,
+    neighbors: impl Fn(T) -> I,
+) -> HashSet<T> {
+    let mut found = HashSet::new();
+    if !open(seed) {
+        return found;
+    }
+    found.insert(seed);
+    let mut queue = VecDeque::from(vec![seed]);
+    while let Some(point) = queue.pop_front() {
+        for neighbor in neighbors(point) {
+            if open(neighbor) && found.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    found
+}
+
+#[test]
+fn flood_fill_2d_matches_breadth_first_search() {
+    let bounds = Box2D::new(Point2D::new(-20, -15), Point2D::new(25, 30));
+    let open = |p: Point2D<i32>| is_open(p.x, p.y, 0);
+    for &neighborhood in &[Neighborhood2D::N4, Neighborhood2D::N8] {
+        for seed in Box2D::new(Point2D::new(0, 0), Point2D::new(4, 4)).points() {
+            let expected = breadth_first(seed, open, |p| {
+                p.neighbors_within(bounds, neighborhood, 1).map(|(p, _)| p)
+            });
+            let points = seed
+                .flood_fill_within(bounds, neighborhood, open)
+                .collect::<Vec<_>>();
+            assert_eq!(expected.len(), points.len());
+            assert_eq!(expected, points.into_iter().collect());
+        }
+    }
+}
+
+#[test]
+fn flood_fill_3d_matches_breadth_first_search() {
+    let bounds = Box3D::new(Point3D::new(-6, -5, -4), Point3D::new(7, 8, 6));
+    let open = |p: Point3D<i32>| is_open(p.x, p.y, p.z);
+    for &neighborhood in &[Neighborhood3D::N6, Neighborhood3D::N18, Neighborhood3D::N26] {
+        for seed in Box3D::new(Point3D::new(0, 0, 0), Point3D::new(2, 2, 2)).points() {
+            let expected = breadth_first(seed, open, |p| {
+                p.neighbors_within(bounds, neighborhood, 1).map(|(p, _)| p)
+            });
+            let points = seed
+                .flood_fill_within(bounds, neighborhood, open)
+                .collect::<Vec<_>>();
+            assert_eq!(expected.len(), points.len());
+            assert_eq!(expected, points.into_iter().collect());
+        }
+    }
+}
+
+#[test]
+fn flood_fill_yields_spans_in_order() {
+    let open = |p: Point2D<i64>| p.x.abs() + p.y.abs() <= 10;
+    let points = Point2D::new(0, 0)
+        .flood_fill(Neighborhood2D::N4, open)
+        .collect::<Vec<_>>();
+    assert_eq!(221, points.len());
+    assert_eq!(
+        (-10..=10).map(|x| Point2D::new(x, 0)).collect::<Vec<_>>(),
+        points[..21]
+    );
+}
+
+#[test]
+fn flood_fill_calls_predicate_a_few_times_per_point() {
+    let calls = Cell::new(0);
+    let open = |p: Point2D<i32>| {
+        calls.set(calls.get() + 1);
+        p.x.abs() <= 50 && p.y.abs() <= 50
+    };
+    let count = Point2D::new(0, 0)
+        .flood_fill(Neighborhood2D::N8, open)
+        .count();
+    assert_eq!(101 * 101, count);
+    assert!(calls.get() <= 4 * count);
+}
+
+#[test]
+fn flood_fill_from_closed_seed() {
+    let bounds = Box2D::new(Point2D::new(0, 0), Point2D::new(10, 10));
+    assert_eq!(
+        0,
+        Point2D::new(3, 3)
+            .flood_fill_within(bounds, Neighborhood2D::N8, |p| p.x != 3)
+            .count()
+    );
+    assert_eq!(
+        0,
+        Point2D::new(-1, 3)
+            .flood_fill_within(bounds, Neighborhood2D::N8, |_| true)
+            .count()
+    );
+}
+
+#[test]
+fn flood_fill_stops_at_limits_of_type() {
+    let points = Point2D::<u8>::new(200, 3)
+        .flood_fill(Neighborhood2D::N4, |p| p.y < 10)
+        .collect::<HashSet<_>>();
+    assert_eq!(256 * 10, points.len());
+    let points = Point3D::<i8>::new(0, 0, 0)
+        .flood_fill(Neighborhood3D::N26, |p| p.x == p.y && p.y == p.z)
+        .count();
+    assert_eq!(256, points);
+}
+
+#[test]
+fn flood_fill_within_inclusive_range() {
+    let bounds = Point2D::new(u8::MAX - 9, 0)..=Point2D::new(u8::MAX, 9);
+    let points = Point2D::new(u8::MAX, 0)
+        .flood_fill_within(bounds.clone(), Neighborhood2D::N4, |_| true)
+        .collect::<HashSet<_>>();
+    assert_eq!(bounds.points().collect::<HashSet<_>>(), points);
+}
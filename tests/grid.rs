@@ -0,0 +1,96 @@
+use euclid::{Box2D, Box3D};
+use euclid_ext::{Grid2D, Grid3D, Points};
+
+enum Space {}
+
+type Point2D = euclid::Point2D<i32, Space>;
+type Point3D = euclid::Point3D<i32, Space>;
+
+#[test]
+fn grid_2d_with_negative_origin() {
+    let bounds = Box2D::new(Point2D::new(-3, -5), Point2D::new(2, -1));
+    let mut grid = Grid2D::from_fn(bounds, |p| (p.x, p.y));
+    assert_eq!(20, grid.len());
+    assert_eq!(bounds, grid.bounds());
+    for p in bounds.points() {
+        assert_eq!((p.x, p.y), grid[p]);
+    }
+    assert_eq!(None, grid.get(Point2D::new(2, -3)));
+    assert_eq!(None, grid.get(Point2D::new(0, -1)));
+    assert_eq!(None, grid.get(Point2D::new(-4, -3)));
+    *grid.get_mut(Point2D::new(-3, -5)).unwrap() = (0, 0);
+    assert_eq!((0, 0), grid.as_slice()[0]);
+}
+
+#[test]
+fn grid_2d_order_matches_points() {
+    let bounds = Box2D::new(Point2D::new(-7, 3), Point2D::new(-2, 9));
+    let grid = Grid2D::from_fn(bounds, |p| p);
+    assert_eq!(bounds.points().collect::<Vec<_>>(), grid.as_slice());
+    assert_eq!(
+        bounds.points().collect::<Vec<_>>(),
+        grid.points().collect::<Vec<_>>()
+    );
+    let range = bounds.points();
+    for (index, (point, &cell)) in grid.iter().enumerate() {
+        assert_eq!(point, cell);
+        assert_eq!(Some(index), grid.index_of(point));
+        assert_eq!(range.index_of(point), grid.index_of(point));
+    }
+}
+
+#[test]
+fn grid_2d_iter_mut() {
+    let bounds = Box2D::new(Point2D::new(-1, -1), Point2D::new(1, 1));
+    let mut grid = Grid2D::new(bounds, 0);
+    for (point, cell) in &mut grid {
+        *cell = point.x * 10 + point.y;
+    }
+    assert_eq!(vec![-11, -1, -10, 0], grid.clone().into_vec());
+    grid[Point2D::new(0, -1)] = 5;
+    assert_eq!(
+        vec![(Point2D::new(-1, -1), &-11), (Point2D::new(0, -1), &5)],
+        (&grid).into_iter().take(2).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn grid_3d_with_negative_origin() {
+    let bounds = Box3D::new(Point3D::new(-2, 0, -4), Point3D::new(1, 2, -1));
+    let grid = Grid3D::from_vec(bounds, (0..18).collect());
+    assert_eq!(
+        bounds.points().collect::<Vec<_>>(),
+        grid.points().collect::<Vec<_>>()
+    );
+    for (index, p) in bounds.points().enumerate() {
+        assert_eq!(index, grid[p]);
+        assert_eq!(Some(index), grid.index_of(p));
+    }
+    assert!(!grid.contains(Point3D::new(0, 0, -1)));
+    assert!(grid.contains(Point3D::new(0, 0, -2)));
+}
+
+#[test]
+fn grid_with_empty_bounds() {
+    let bounds = Box2D::new(Point2D::new(3, 3), Point2D::new(1, 5));
+    let grid = Grid2D::new(bounds, 1u8);
+    assert!(grid.is_empty());
+    assert_eq!(0, grid.iter().count());
+    assert_eq!(None, grid.get(Point2D::new(2, 4)));
+}
+
+#[test]
+#[should_panic(expected = "out of the grid bounds")]
+fn grid_index_out_of_bounds() {
+    let grid = Grid2D::new(Box2D::new(Point2D::new(0, 0), Point2D::new(2, 2)), 0);
+    let _ = grid[Point2D::new(2, 0)];
+}
+
+#[test]
+#[should_panic(expected = "one element per cell")]
+fn grid_from_vec_with_wrong_length() {
+    Grid3D::from_vec(
+        Box3D::new(Point3D::new(0, 0, 0), Point3D::new(2, 2, 2)),
+        vec![0; 7],
+    );
+}
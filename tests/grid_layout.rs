@@ -0,0 +1,129 @@
+use euclid::{Box2D, Box3D, Size2D};
+use euclid_ext::{Grid3D, GridLayout2D, GridLayout3D, Order2D, Order3D, Points};
+
+enum Space {}
+
+type Point2D = euclid::Point2D<i32, Space>;
+type Point3D = euclid::Point3D<i32, Space>;
+
+#[test]
+fn grid_layout_2d_matches_point_range_iterator() {
+    let bounds = Box2D::new(Point2D::new(-4, 3), Point2D::new(3, 8));
+    for &order in &[Order2D::XY, Order2D::YX] {
+        let layout = GridLayout2D::with_order(bounds, order);
+        let range = bounds.points_in_order(order);
+        assert_eq!(range.len(), layout.len());
+        assert_eq!(range.len(), layout.buffer_len());
+        assert_eq!(order, layout.order());
+        assert_eq!(
+            range.clone().collect::<Vec<_>>(),
+            layout.points().collect::<Vec<_>>()
+        );
+        for (index, point) in range.clone().enumerate() {
+            assert_eq!(Some(index), layout.index_of(point));
+            assert_eq!(Some(point), layout.point_at(index));
+        }
+        assert_eq!(None, layout.point_at(range.len()));
+        for &point in &[Point2D::new(3, 3), Point2D::new(-5, 5), Point2D::new(0, 8)] {
+            assert!(!layout.contains(point));
+            assert_eq!(None, layout.index_of(point));
+        }
+    }
+}
+
+#[test]
+fn grid_layout_3d_matches_point_range_iterator() {
+    let bounds = Box3D::new(Point3D::new(-2, 0, 5), Point3D::new(1, 4, 7));
+    let orders = [
+        Order3D::XYZ,
+        Order3D::XZY,
+        Order3D::YXZ,
+        Order3D::YZX,
+        Order3D::ZXY,
+        Order3D::ZYX,
+    ];
+    for &order in &orders {
+        let layout = GridLayout3D::with_order(bounds, order);
+        let range = bounds.points_in_order(order);
+        assert_eq!(
+            range.clone().collect::<Vec<_>>(),
+            layout.points().collect::<Vec<_>>()
+        );
+        for (index, point) in range.enumerate() {
+            assert_eq!(Some(index), layout.index_of(point));
+            assert_eq!(Some(point), layout.point_at(index));
+        }
+        assert_eq!(layout, GridLayout3D::with_strides(bounds, layout.strides()));
+        assert_eq!(
+            order,
+            GridLayout3D::with_strides(bounds, layout.strides()).order()
+        );
+    }
+    let grid = Grid3D::from_fn(bounds, |p| p);
+    assert_eq!(GridLayout3D::new(bounds), grid.layout());
+    for (index, &point) in grid.as_slice().iter().enumerate() {
+        assert_eq!(Some(index), grid.index_of(point));
+    }
+}
+
+#[test]
+fn grid_layout_with_padded_strides() {
+    let layout = GridLayout2D::from_size(Size2D::<i32, Space>::new(5, 3));
+    assert_eq!([1, 5], layout.strides());
+    let bounds = layout.bounds();
+    let padded = GridLayout2D::with_strides(bounds, [1, 8]);
+    assert_eq!(15, padded.len());
+    assert_eq!(21, padded.buffer_len());
+    let mut indices = Vec::new();
+    for point in padded.points() {
+        let index = padded.index_of(point).unwrap();
+        assert_eq!(Some(point), padded.point_at(index));
+        indices.push(index);
+    }
+    assert!(indices.windows(2).all(|pair| pair[0] < pair[1]));
+    for index in 0..padded.buffer_len() + 3 {
+        assert_eq!(indices.contains(&index), padded.point_at(index).is_some());
+    }
+
+    let interleaved = GridLayout3D::with_strides(
+        Box3D::new(Point3D::new(0, 0, 0), Point3D::new(2, 3, 1)),
+        [3, 6, 100],
+    );
+    assert_eq!(Order3D::XYZ, interleaved.order());
+    assert_eq!(16, interleaved.buffer_len());
+    assert_eq!(Some(15), interleaved.index_of(Point3D::new(1, 2, 0)));
+    assert_eq!(Some(Point3D::new(1, 2, 0)), interleaved.point_at(15));
+    assert_eq!(None, interleaved.point_at(14));
+}
+
+#[test]
+fn grid_layout_with_empty_bounds() {
+    let layout = GridLayout2D::new(Box2D::new(Point2D::new(3, 0), Point2D::new(1, 5)));
+    assert!(layout.is_empty());
+    assert_eq!(0, layout.buffer_len());
+    assert_eq!(None, layout.point_at(0));
+    assert_eq!(None, layout.index_of(Point2D::new(2, 2)));
+    assert_eq!(0, layout.points().count());
+}
+
+#[test]
+#[should_panic(expected = "strides do not nest")]
+fn grid_layout_with_overlapping_strides() {
+    GridLayout2D::with_strides(Box2D::new(Point2D::new(0, 0), Point2D::new(4, 4)), [1, 3]);
+}
+
+#[test]
+#[should_panic(expected = "strides do not nest")]
+fn grid_layout_with_interleaved_strides() {
+    GridLayout2D::with_strides(Box2D::new(Point2D::new(0, 0), Point2D::new(3, 2)), [2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "more than usize::MAX points")]
+fn grid_layout_with_too_many_points() {
+    let bounds = Box3D::new(
+        Point3D::new(i32::MIN, i32::MIN, i32::MIN),
+        Point3D::new(i32::MAX, i32::MAX, i32::MAX),
+    );
+    GridLayout3D::new(bounds);
+}
@@ -0,0 +1,205 @@
+use euclid::{Box2D, Box3D, SideOffsets2D, Size3D};
+use euclid_ext::{Grid2D, Grid3D, Points};
+
+enum Space {}
+
+type Point2D = euclid::Point2D<i32, Space>;
+type Point3D = euclid::Point3D<i32, Space>;
+
+type Case<G, P> = (G, fn(P) -> P);
+
+fn grid_2d() -> Grid2D<Point2D, Space> {
+    Grid2D::from_fn(Box2D::new(Point2D::new(-3, 2), Point2D::new(2, 6)), |p| p)
+}
+
+fn grid_3d() -> Grid3D<Point3D, Space> {
+    Grid3D::from_fn(
+        Box3D::new(Point3D::new(-2, 1, -4), Point3D::new(1, 5, -2)),
+        |p| p,
+    )
+}
+
+#[test]
+fn grid_2d_transforms_move_cells() {
+    let grid = grid_2d();
+    let cases: [Case<Grid2D<Point2D, Space>, Point2D>; 6] = [
+        (grid.rotate_90(), |p| Point2D::new(-p.y - 1, p.x)),
+        (grid.rotate_180(), |p| Point2D::new(-p.x - 1, -p.y - 1)),
+        (grid.rotate_270(), |p| Point2D::new(p.y, -p.x - 1)),
+        (grid.flip_x(), |p| Point2D::new(-p.x - 1, p.y)),
+        (grid.flip_y(), |p| Point2D::new(p.x, -p.y - 1)),
+        (grid.transpose(), |p| Point2D::new(p.y, p.x)),
+    ];
+    for (transformed, f) in cases.iter() {
+        assert_eq!(grid.len(), transformed.len());
+        let moved = Box2D::from_points(grid.points().map(f));
+        assert_eq!(moved.max, transformed.bounds().max - euclid::vec2(1, 1));
+        assert_eq!(moved.min, transformed.bounds().min);
+        for (point, &cell) in grid.iter() {
+            assert_eq!(cell, transformed[f(point)]);
+        }
+    }
+}
+
+#[test]
+fn grid_2d_transforms_compose() {
+    let grid = grid_2d();
+    assert_eq!(grid, grid.rotate_90().rotate_90().rotate_90().rotate_90());
+    assert_eq!(grid.rotate_180(), grid.rotate_90().rotate_90());
+    assert_eq!(grid.rotate_270(), grid.rotate_180().rotate_90());
+    assert_eq!(grid.rotate_180(), grid.flip_x().flip_y());
+    assert_eq!(grid, grid.transpose().transpose());
+    assert_eq!(grid, grid.flip_y().flip_y());
+}
+
+#[test]
+fn grid_3d_transforms_move_cells() {
+    let grid = grid_3d();
+    let cases: [Case<Grid3D<Point3D, Space>, Point3D>; 9] = [
+        (grid.rotate_90_x(), |p| Point3D::new(p.x, -p.z - 1, p.y)),
+        (grid.rotate_90_y(), |p| Point3D::new(p.z, p.y, -p.x - 1)),
+        (grid.rotate_90_z(), |p| Point3D::new(-p.y - 1, p.x, p.z)),
+        (grid.flip_x(), |p| Point3D::new(-p.x - 1, p.y, p.z)),
+        (grid.flip_y(), |p| Point3D::new(p.x, -p.y - 1, p.z)),
+        (grid.flip_z(), |p| Point3D::new(p.x, p.y, -p.z - 1)),
+        (grid.transpose_xy(), |p| Point3D::new(p.y, p.x, p.z)),
+        (grid.transpose_xz(), |p| Point3D::new(p.z, p.y, p.x)),
+        (grid.transpose_yz(), |p| Point3D::new(p.x, p.z, p.y)),
+    ];
+    for (transformed, f) in cases.iter() {
+        assert_eq!(grid.len(), transformed.len());
+        let mut points = grid.points().map(f).collect::<Vec<_>>();
+        let mut expected = transformed.bounds().points().collect::<Vec<_>>();
+        points.sort_by_key(|p| (p.z, p.y, p.x));
+        expected.sort_by_key(|p| (p.z, p.y, p.x));
+        assert_eq!(expected, points);
+        for (point, &cell) in grid.iter() {
+            assert_eq!(cell, transformed[f(point)]);
+        }
+    }
+    assert_eq!(
+        grid,
+        grid.rotate_90_y().rotate_90_y().rotate_90_y().rotate_90_y()
+    );
+}
+
+#[test]
+fn grid_crop_resize_and_pad() {
+    let grid = grid_2d();
+    let cropped = grid.crop(Box2D::new(Point2D::new(0, -10), Point2D::new(10, 4)));
+    assert_eq!(
+        Box2D::new(Point2D::new(0, 2), Point2D::new(2, 4)),
+        cropped.bounds()
+    );
+    assert!(cropped.iter().all(|(point, &cell)| point == cell));
+    assert!(grid
+        .crop(Box2D::new(Point2D::new(5, 5), Point2D::new(8, 8)))
+        .is_empty());
+
+    let bounds = Box2D::new(Point2D::new(1, 0), Point2D::new(4, 3));
+    let resized = grid.resize(bounds, Point2D::new(99, 99));
+    assert_eq!(bounds, resized.bounds());
+    for (point, &cell) in resized.iter() {
+        let expected = if grid.contains(point) {
+            point
+        } else {
+            Point2D::new(99, 99)
+        };
+        assert_eq!(expected, cell);
+    }
+
+    let padded = grid.pad(SideOffsets2D::new(1, 2, 3, 4), Point2D::new(0, 0));
+    assert_eq!(
+        Box2D::new(Point2D::new(-7, 1), Point2D::new(4, 9)),
+        padded.bounds()
+    );
+    assert_eq!(grid, padded.crop(grid.bounds()));
+    assert_eq!(
+        padded.len() - grid.len(),
+        padded
+            .iter()
+            .filter(|&(_, &cell)| cell == Point2D::new(0, 0))
+            .count()
+    );
+
+    let grid = grid_3d();
+    let padded = grid.pad(
+        Size3D::new(1, 0, 2),
+        Size3D::new(0, 3, 1),
+        Point3D::new(0, 0, 0),
+    );
+    assert_eq!(
+        Box3D::new(Point3D::new(-3, 1, -6), Point3D::new(1, 8, -1)),
+        padded.bounds()
+    );
+    assert_eq!(grid, padded.crop(grid.bounds()));
+    assert_eq!(
+        padded.bounds(),
+        grid.resize(padded.bounds(), Point3D::new(0, 0, 0)).bounds()
+    );
+}
+
+#[test]
+fn grid_transforms_of_empty_bounds() {
+    let grid = Grid2D::new(Box2D::new(Point2D::new(3, 3), Point2D::new(1, 5)), 1u8);
+    for transformed in &[
+        grid.transpose(),
+        grid.rotate_90(),
+        grid.rotate_180(),
+        grid.flip_x(),
+    ] {
+        assert!(transformed.is_empty());
+        assert!(transformed.bounds().is_empty_or_negative());
+    }
+    assert_eq!(
+        Box2D::new(Point2D::new(3, 3), Point2D::new(5, 1)),
+        grid.transpose().bounds()
+    );
+    let grid = Grid3D::new(
+        Box3D::new(Point3D::new(0, 4, 0), Point3D::new(2, 4, 2)),
+        1u8,
+    );
+    assert!(grid.rotate_90_x().is_empty());
+    assert!(grid.transpose_xy().is_empty());
+}
+
+#[test]
+fn grid_transforms_at_limits_of_i32() {
+    let bounds = Box2D::new(
+        Point2D::new(i32::MAX - 2, i32::MIN + 1),
+        Point2D::new(i32::MAX, i32::MIN + 3),
+    );
+    let grid = Grid2D::from_fn(bounds, |p| p);
+    let rotated = grid.rotate_180();
+    assert_eq!(
+        Box2D::new(
+            Point2D::new(-i32::MAX, i32::MAX - 2),
+            Point2D::new(2 - i32::MAX, i32::MAX)
+        ),
+        rotated.bounds()
+    );
+    assert_eq!(grid, rotated.rotate_180());
+}
+
+#[test]
+#[should_panic(expected = "mirrored bounds are out of the range of i32")]
+fn grid_flip_at_minimum_of_i32() {
+    Grid2D::new(
+        Box2D::new(Point2D::new(0, i32::MIN), Point2D::new(1, i32::MIN + 1)),
+        0,
+    )
+    .flip_y();
+}
+
+#[test]
+#[should_panic(expected = "padded bounds are out of the range of i32")]
+fn grid_pad_past_maximum_of_i32() {
+    Grid3D::new(
+        Box3D::new(
+            Point3D::new(0, 0, i32::MAX - 1),
+            Point3D::new(1, 1, i32::MAX),
+        ),
+        0,
+    )
+    .pad(Size3D::new(0, 0, 0), Size3D::new(0, 0, 1), 0);
+}
@@ -0,0 +1,151 @@
+use euclid::{Box2D, Size2D};
+use euclid_ext::{Grid2D, Points, Tiles};
+use std::thread;
+
+enum Space {}
+
+type Point2D = euclid::Point2D<i32, Space>;
+
+fn value(p: Point2D) -> i32 {
+    p.x * 100 + p.y
+}
+
+#[test]
+fn grid_view_matches_grid() {
+    let grid = Grid2D::from_fn(Box2D::new(Point2D::new(-5, -4), Point2D::new(6, 3)), value);
+    let bounds = Box2D::new(Point2D::new(-2, -3), Point2D::new(4, 1));
+    let view = grid.view(bounds);
+    assert_eq!(24, view.len());
+    assert_eq!(bounds, view.bounds());
+    assert_eq!(
+        bounds.points().collect::<Vec<_>>(),
+        view.points().collect::<Vec<_>>()
+    );
+    for (point, &cell) in view.iter() {
+        assert_eq!(value(point), cell);
+        assert_eq!(Some(&cell), view.get_local(view.to_local(point)));
+        assert_eq!(point, view.to_global(view.to_local(point)));
+    }
+    for point in grid.bounds().points() {
+        let expected = if bounds.contains(point) {
+            Some(&grid[point])
+        } else {
+            None
+        };
+        assert_eq!(expected, view.get(point));
+    }
+    assert_eq!(None, view.get_local(Point2D::new(-1, 0)));
+    assert_eq!(None, view.get_local(Point2D::new(6, 0)));
+    let inner = Box2D::new(Point2D::new(0, -1), Point2D::new(2, 1));
+    assert_eq!(grid.view(inner).to_grid(), view.view(inner).to_grid());
+    assert_eq!(Grid2D::from_fn(inner, value), view.view(inner).to_grid());
+}
+
+#[test]
+fn grid_view_mut_splits_into_disjoint_views() {
+    let bounds = Box2D::new(Point2D::new(-3, -2), Point2D::new(4, 5));
+    let mut grid = Grid2D::new(bounds, 0);
+    let (top, bottom) = grid.as_view_mut().split_at_y(1);
+    let (mut top_left, mut top_right) = top.split_at_x(-3);
+    let (mut bottom_left, mut bottom_right) = bottom.split_at_x(0);
+    assert!(top_left.is_empty());
+    assert_eq!(21, top_right.len());
+    for view in [&mut top_right, &mut bottom_left, &mut bottom_right].iter_mut() {
+        for (point, cell) in view.iter_mut() {
+            *cell += value(point);
+        }
+    }
+    top_left.fill(1);
+    bottom_left[Point2D::new(-1, 4)] = 7;
+    *bottom_right.get_mut(Point2D::new(3, 1)).unwrap() = 8;
+    assert_eq!(None, bottom_right.get_mut(Point2D::new(-1, 1)));
+    assert_eq!(
+        Box2D::new(Point2D::new(0, 1), Point2D::new(4, 5)),
+        bottom_right.bounds()
+    );
+    for (point, &cell) in grid.iter() {
+        let expected = match (point.x, point.y) {
+            (-1, 4) => 7,
+            (3, 1) => 8,
+            _ => value(point),
+        };
+        assert_eq!(expected, cell);
+    }
+}
+
+#[test]
+fn grid_tiles_mut_match_tiles() {
+    let bounds = Box2D::new(Point2D::new(-7, -3), Point2D::new(6, 8));
+    let size = Size2D::new(4, 3);
+    let mut grid = Grid2D::new(bounds, 0);
+    let tiles = grid.tiles_mut(size);
+    assert_eq!(
+        bounds.tiles(size).collect::<Vec<_>>(),
+        tiles.iter().map(|tile| tile.bounds()).collect::<Vec<_>>()
+    );
+    thread::scope(|scope| {
+        for mut tile in tiles {
+            scope.spawn(move || {
+                for point in tile.points() {
+                    tile[point] = value(point);
+                }
+            });
+        }
+    });
+    assert_eq!(Grid2D::from_fn(bounds, value), grid);
+    let empty = Box2D::new(Point2D::new(2, 2), Point2D::new(2, 9));
+    assert!(Grid2D::new(empty, 0).tiles_mut(size).is_empty());
+}
+
+#[test]
+fn grid_view_copy_and_blit() {
+    let source = Grid2D::from_fn(
+        Box2D::new(Point2D::new(10, 10), Point2D::new(13, 12)),
+        value,
+    );
+    let bounds = Box2D::new(Point2D::new(0, 0), Point2D::new(5, 4));
+    let mut grid = Grid2D::new(bounds, 0);
+    grid.view_mut(Box2D::new(Point2D::new(2, 1), Point2D::new(5, 3)))
+        .copy_from(&source.as_view());
+    assert_eq!(1010, grid[Point2D::new(2, 1)]);
+    assert_eq!(1211, grid[Point2D::new(4, 2)]);
+    assert_eq!(0, grid[Point2D::new(1, 1)]);
+
+    let mut grid = Grid2D::new(bounds, 0);
+    grid.as_view_mut()
+        .blit(&source.as_view(), Point2D::new(-1, 3));
+    let blitted = grid
+        .iter()
+        .filter(|&(_, &cell)| cell != 0)
+        .map(|(point, &cell)| (point, cell))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![(Point2D::new(0, 3), 1110), (Point2D::new(1, 3), 1210)],
+        blitted
+    );
+    grid.as_view_mut()
+        .blit(&source.as_view(), Point2D::new(i32::MAX, i32::MIN));
+    grid.as_view_mut()
+        .blit(&source.as_view(), Point2D::new(5, 0));
+    assert_eq!(2, grid.iter().filter(|&(_, &cell)| cell != 0).count());
+
+    let (mut left, right) = grid.as_view_mut().split_at_x(2);
+    left.blit(&right.as_view(), Point2D::new(-2, 0));
+    assert_eq!(1210, grid[Point2D::new(1, 3)]);
+    assert_eq!(0, grid[Point2D::new(0, 3)]);
+}
+
+#[test]
+#[should_panic(expected = "out of the bounds")]
+fn grid_view_out_of_bounds() {
+    let grid = Grid2D::new(Box2D::new(Point2D::new(0, 0), Point2D::new(4, 4)), 0);
+    grid.view(Box2D::new(Point2D::new(1, 1), Point2D::new(5, 2)));
+}
+
+#[test]
+#[should_panic(expected = "same size")]
+fn grid_view_copy_from_different_size() {
+    let source = Grid2D::new(Box2D::new(Point2D::new(0, 0), Point2D::new(2, 2)), 1);
+    let mut grid = Grid2D::new(Box2D::new(Point2D::new(0, 0), Point2D::new(3, 2)), 0);
+    grid.as_view_mut().copy_from(&source.as_view());
+}
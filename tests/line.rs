@@ -0,0 +1,141 @@
+use euclid::Box2D;
+use euclid_ext::{LineMode, LinePoints, Points};
+use std::collections::HashSet;
+
+enum Space {}
+
+type Point2D<T> = euclid::Point2D<T, Space>;
+
+fn endpoints() -> Vec<(Point2D<i32>, Point2D<i32>)> {
+    let points = Box2D::new(Point2D::new(-3, -2), Point2D::new(4, 3))
+        .points()
+        .collect::<Vec<_>>();
+    points
+        .iter()
+        .flat_map(|&a| points.iter().map(move |&b| (a, b)))
+        .collect()
+}
+
+// Whether the segment between the centers of `a` and `b` touches the cell at `c`,
+// computed exactly with the range of the segment's parameter inside the cell along each axis.
+fn touches(a: Point2D<i32>, b: Point2D<i32>, c: Point2D<i32>) -> bool {
+    let mut ranges = Vec::new();
+    for &(a, b, c) in &[(a.x, b.x, c.x), (a.y, b.y, c.y)] {
+        let d = i64::from(b - a);
+        let offset = 2 * i64::from(c - a);
+        if d == 0 {
+            if offset != 0 {
+                return false;
+            }
+        } else {
+            let (low, high) = ((offset - 1, 2 * d), (offset + 1, 2 * d));
+            let (low, high) = if d > 0 {
+                (low, high)
+            } else {
+                ((-high.0, -high.1), (-low.0, -low.1))
+            };
+            ranges.push((low, high));
+        }
+    }
+    ranges.push(((0, 1), (1, 1)));
+    ranges.iter().all(|&(low, _)| {
+        ranges
+            .iter()
+            .all(|&(_, high)| low.0 * high.1 <= high.0 * low.1)
+    })
+}
+
+#[test]
+fn bresenham_line() {
+    assert_eq!(
+        vec![
+            Point2D::new(0, 0),
+            Point2D::new(1, 1),
+            Point2D::new(2, 1),
+            Point2D::new(3, 2),
+            Point2D::new(4, 2)
+        ],
+        Point2D::new(0, 0)
+            .line_points(Point2D::new(4, 2), LineMode::Bresenham)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn bresenham_lines_are_connected() {
+    for (a, b) in endpoints() {
+        let points = a.line_points(b, LineMode::Bresenham).collect::<Vec<_>>();
+        let d = b - a;
+        assert_eq!(d.x.abs().max(d.y.abs()) as usize + 1, points.len());
+        assert_eq!(Some(&a), points.first());
+        assert_eq!(Some(&b), points.last());
+        for pair in points.windows(2) {
+            let step = pair[1] - pair[0];
+            assert_eq!(1, step.x.abs().max(step.y.abs()));
+        }
+    }
+}
+
+#[test]
+fn supercover_line() {
+    assert_eq!(
+        vec![
+            Point2D::new(0, 0),
+            Point2D::new(1, 0),
+            Point2D::new(1, 1),
+            Point2D::new(2, 1),
+            Point2D::new(2, 2),
+            Point2D::new(3, 2)
+        ],
+        Point2D::new(0, 0)
+            .line_points(Point2D::new(3, 2), LineMode::Supercover)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn supercover_lines_cover_touched_cells() {
+    for (a, b) in endpoints() {
+        let points = a.line_points(b, LineMode::Supercover).collect::<Vec<_>>();
+        let expected = Box2D::new(a.min(b), a.max(b) + euclid::Vector2D::new(1, 1))
+            .points()
+            .filter(|&c| touches(a, b, c))
+            .collect::<HashSet<_>>();
+        assert_eq!(expected.len(), points.len());
+        assert_eq!(expected, points.into_iter().collect::<HashSet<_>>());
+    }
+}
+
+#[test]
+fn lines_are_symmetric() {
+    for &mode in &[LineMode::Bresenham, LineMode::Supercover] {
+        for (a, b) in endpoints() {
+            let mut points = b.line_points(a, mode).collect::<Vec<_>>();
+            points.reverse();
+            assert_eq!(points, a.line_points(b, mode).collect::<Vec<_>>());
+        }
+    }
+}
+
+#[test]
+fn line_of_single_point() {
+    for &mode in &[LineMode::Bresenham, LineMode::Supercover] {
+        assert_eq!(
+            vec![Point2D::new(5, -5)],
+            Point2D::new(5, -5)
+                .line_points(Point2D::new(5, -5), mode)
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn line_across_whole_type() {
+    let a = Point2D::new(i32::MIN, i32::MIN);
+    let b = Point2D::new(i32::MAX, i32::MAX);
+    for &mode in &[LineMode::Bresenham, LineMode::Supercover] {
+        let mut i = a.line_points(b, mode);
+        assert_eq!(Some(a), i.next());
+        assert_eq!(Some(b), i.last());
+    }
+}
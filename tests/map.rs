@@ -15,6 +15,8 @@ type Rect<T> = euclid::Rect<T, Space>;
 
 type Point3D<T> = euclid::Point3D<T, Space>;
 
+type Size3D<T> = euclid::Size3D<T, Space>;
+
 type Vector3D<T> = euclid::Vector3D<T, Space>;
 
 type Box3D<T> = euclid::Box3D<T, Space>;
@@ -102,6 +104,14 @@ fn map_point3d() {
     assert_eq!(Point3D::new(2, 4, 6), Point3D::new(1, 2, 3).map(|n| n * 2));
 }
 
+#[test]
+fn map_size3d() {
+    assert_eq!(
+        Size3D::new(13, 17, 19),
+        Size3D::new(3, 7, 9).map(|n| n + Length::new(10))
+    );
+}
+
 #[test]
 fn map_vector3d() {
     assert_eq!(
@@ -0,0 +1,138 @@
+use euclid::{Box2D, Box3D};
+use euclid_ext::{Neighborhood2D, Neighborhood3D, Neighbors};
+
+enum Space {}
+
+type Point2D<T> = euclid::Point2D<T, Space>;
+
+type Point3D<T> = euclid::Point3D<T, Space>;
+
+type Vector2D<T> = euclid::Vector2D<T, Space>;
+
+#[test]
+fn neighbors_n8() {
+    assert_eq!(
+        vec![
+            (Point2D::new(4, 6), Vector2D::new(-1, -1)),
+            (Point2D::new(5, 6), Vector2D::new(0, -1)),
+            (Point2D::new(6, 6), Vector2D::new(1, -1)),
+            (Point2D::new(4, 7), Vector2D::new(-1, 0)),
+            (Point2D::new(6, 7), Vector2D::new(1, 0)),
+            (Point2D::new(4, 8), Vector2D::new(-1, 1)),
+            (Point2D::new(5, 8), Vector2D::new(0, 1)),
+            (Point2D::new(6, 8), Vector2D::new(1, 1))
+        ],
+        Point2D::new(5, 7)
+            .neighbors(Neighborhood2D::N8, 1)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn neighbors_with_radius() {
+    let center = Point2D::new(0, 0);
+    assert_eq!(12, center.neighbors(Neighborhood2D::N4, 2).count());
+    assert_eq!(24, center.neighbors(Neighborhood2D::N8, 2).count());
+    let center = Point3D::new(0i32, 0, 0);
+    assert_eq!(24, center.neighbors(Neighborhood3D::N6, 2).count());
+    assert_eq!(124, center.neighbors(Neighborhood3D::N26, 2).count());
+    assert!(center
+        .neighbors(Neighborhood3D::N6, 3)
+        .all(|(p, v)| p == center + v && v.x.abs() + v.y.abs() + v.z.abs() <= 3));
+}
+
+#[test]
+fn neighbors_n18() {
+    let neighbors = Point3D::new(1i32, 2, 3)
+        .neighbors(Neighborhood3D::N18, 1)
+        .collect::<Vec<_>>();
+    assert_eq!(18, neighbors.len());
+    assert!(neighbors
+        .iter()
+        .all(|(_, v)| v.x.abs() + v.y.abs() + v.z.abs() <= 2));
+}
+
+#[test]
+fn neighbors_within_bounds() {
+    let bounds = Box2D::new(Point2D::new(0, 0), Point2D::new(3, 3));
+    assert_eq!(
+        vec![Point2D::new(1, 0), Point2D::new(0, 1), Point2D::new(1, 1)],
+        Point2D::new(0, 0)
+            .neighbors_within(bounds, Neighborhood2D::N8, 1)
+            .map(|(p, _)| p)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        0,
+        Point2D::new(10, 10)
+            .neighbors_within(bounds, Neighborhood2D::N8, 1)
+            .count()
+    );
+    let bounds = Box3D::new(Point3D::new(0, 0, 0), Point3D::new(4, 4, 1));
+    assert_eq!(
+        4,
+        Point3D::new(1, 1, 0)
+            .neighbors_within(bounds, Neighborhood3D::N6, 1)
+            .count()
+    );
+}
+
+#[test]
+fn neighbors_in_reverse() {
+    let center = Point3D::new(0, 0, 0);
+    let mut neighbors = center.neighbors(Neighborhood3D::N18, 2).collect::<Vec<_>>();
+    neighbors.reverse();
+    assert_eq!(
+        neighbors,
+        center
+            .neighbors(Neighborhood3D::N18, 2)
+            .rev()
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn neighbors_at_bounds_of_type() {
+    assert_eq!(
+        vec![
+            Point2D::new(i8::MAX - 1, i8::MIN),
+            Point2D::new(i8::MAX - 1, i8::MIN + 1),
+            Point2D::new(i8::MAX, i8::MIN + 1)
+        ],
+        Point2D::new(i8::MAX, i8::MIN)
+            .neighbors(Neighborhood2D::N8, 1)
+            .map(|(p, _)| p)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn neighbors_of_floats() {
+    assert_eq!(
+        vec![Point2D::new(0.5, -0.5), Point2D::new(-0.5, 0.5)],
+        Point2D::new(0.5, 0.5)
+            .neighbors_within(
+                Box2D::new(Point2D::new(-1.0, -1.0), Point2D::new(1.0, 1.0)),
+                Neighborhood2D::N4,
+                1
+            )
+            .map(|(p, _)| p)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn neighbors_within_inclusive_range() {
+    let bounds = Point2D::new(0i8, 0)..=Point2D::new(i8::MAX, i8::MAX);
+    assert_eq!(
+        vec![
+            Point2D::new(i8::MAX - 1, i8::MAX - 1),
+            Point2D::new(i8::MAX, i8::MAX - 1),
+            Point2D::new(i8::MAX - 1, i8::MAX)
+        ],
+        Point2D::new(i8::MAX, i8::MAX)
+            .neighbors_within(bounds, Neighborhood2D::N8, 1)
+            .map(|(p, _)| p)
+            .collect::<Vec<_>>()
+    );
+}
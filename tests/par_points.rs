@@ -0,0 +1,63 @@
+#![cfg(feature = "rayon")]
+
+use euclid::{Box2D, Box3D};
+use euclid_ext::{ParPoints, Points};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+enum Space {}
+
+type Point2D<T> = euclid::Point2D<T, Space>;
+
+type Point3D<T> = euclid::Point3D<T, Space>;
+
+type Vector2D<T> = euclid::Vector2D<T, Space>;
+
+#[test]
+fn par_points_in_box2d() {
+    let range = Box2D::new(Point2D::new(-30, 10), Point2D::new(70, 90));
+    let points = range.par_points();
+    assert_eq!(8000, points.len());
+    assert_eq!(
+        range.points().collect::<Vec<_>>(),
+        points.collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn par_points_in_box3d() {
+    let range = Box3D::new(Point3D::new(1, 2, 3), Point3D::new(21, 32, 43));
+    assert_eq!(
+        range.points().collect::<Vec<_>>(),
+        range.par_points().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        range
+            .points()
+            .filter(|p| (p.x + p.y + p.z) % 7 == 0)
+            .count(),
+        range
+            .par_points()
+            .filter(|p| (p.x + p.y + p.z) % 7 == 0)
+            .count()
+    );
+}
+
+#[test]
+fn par_points_in_inclusive_range() {
+    let range = Point2D::new(0u8, 0)..=Point2D::new(u8::MAX, u8::MAX);
+    assert_eq!(
+        range.clone().points().collect::<Vec<_>>(),
+        range.par_points().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn par_points_from_partially_consumed_iterator() {
+    let mut points = (Point2D::new(0, 0)..Point2D::new(100, 100)).points_by(Vector2D::new(3, 7));
+    points.next();
+    points.next_back();
+    assert_eq!(
+        points.clone().rev().collect::<Vec<_>>(),
+        points.into_par_iter().rev().collect::<Vec<_>>()
+    );
+}
@@ -1,5 +1,7 @@
 use euclid::{Box2D, Box3D, Rect, Size2D};
-use euclid_ext::Points;
+use euclid_ext::point_range_iterator::{PointRange, PointRangeIterator, PointRangeIteratorItem};
+use euclid_ext::{Order2D, Order3D, Points, ToPointRange};
+use std::ops;
 
 enum Space {}
 
@@ -7,6 +9,10 @@ type Point2D<T> = euclid::Point2D<T, Space>;
 
 type Point3D<T> = euclid::Point3D<T, Space>;
 
+type Vector2D<T> = euclid::Vector2D<T, Space>;
+
+type Vector3D<T> = euclid::Vector3D<T, Space>;
+
 macro_rules! point_vec {
     ($(($x:expr, $y:expr, $z:expr)),*) => {
         vec![$(Point3D::new($x, $y, $z)),*]
@@ -128,3 +134,525 @@ fn points_in_box3d() {
             .collect::<Vec<_>>()
     );
 }
+
+#[test]
+fn points_len_2d() {
+    let mut points = Box2D::new(Point2D::new(5, 10), Point2D::new(8, 12)).points();
+    assert_eq!((6, Some(6)), points.size_hint());
+    for remaining in (0..6).rev() {
+        points.next();
+        assert_eq!(remaining, points.len());
+    }
+    assert_eq!(None, points.next());
+    assert_eq!(0, points.len());
+}
+
+#[test]
+fn points_len_3d() {
+    let mut points = (Point3D::new(3, 5, 7)..Point3D::new(5, 8, 11)).points();
+    assert_eq!(24, points.len());
+    for remaining in (0..24).rev() {
+        points.next();
+        assert_eq!(remaining, points.len());
+    }
+    assert_eq!(None, points.next());
+    assert_eq!(0, points.len());
+}
+
+#[test]
+fn points_len_float() {
+    let points = (Point2D::new(0.5, 1.0)..Point2D::new(3.0, 3.0)).points();
+    assert_eq!(6, points.len());
+    assert_eq!(6, points.count());
+}
+
+#[test]
+fn points_in_empty_range() {
+    let mut points = (Point2D::new(3, 5)..Point2D::new(3, 8)).points();
+    assert_eq!(0, points.len());
+    assert_eq!(None, points.next());
+    let mut points = (Point3D::new(3, 5, 7)..Point3D::new(5, 8, 7)).points();
+    assert_eq!(0, points.len());
+    assert_eq!(None, points.next());
+}
+
+#[test]
+fn points_in_inverted_range() {
+    let mut points = (Point2D::new(5, 8)..Point2D::new(3, 10)).points();
+    assert_eq!(0, points.len());
+    assert_eq!(None, points.next());
+    let mut points = (Point3D::new(3, 9, 7)..Point3D::new(5, 8, 9)).points();
+    assert_eq!(0, points.len());
+    assert_eq!(None, points.next());
+    assert_eq!(None, points.next());
+}
+
+#[test]
+fn points_in_reverse_2d() {
+    assert_eq!(
+        point_vec![(7, 11), (6, 11), (5, 11), (7, 10), (6, 10), (5, 10)],
+        Box2D::new(Point2D::new(5, 10), Point2D::new(8, 12))
+            .points()
+            .rev()
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn points_in_reverse_3d() {
+    let range = Point3D::new(3, 5, 7)..Point3D::new(5, 8, 11);
+    let mut expected = range.clone().points().collect::<Vec<_>>();
+    expected.reverse();
+    assert_eq!(expected, range.points().rev().collect::<Vec<_>>());
+}
+
+#[test]
+fn points_from_both_ends_2d() {
+    let mut points = (Point2D::new(0, 0)..Point2D::new(3, 2)).points();
+    assert_eq!(Some(Point2D::new(0, 0)), points.next());
+    assert_eq!(Some(Point2D::new(2, 1)), points.next_back());
+    assert_eq!(Some(Point2D::new(1, 1)), points.next_back());
+    assert_eq!(Some(Point2D::new(1, 0)), points.next());
+    assert_eq!(2, points.len());
+    assert_eq!(Some(Point2D::new(0, 1)), points.next_back());
+    assert_eq!(Some(Point2D::new(2, 0)), points.next());
+    assert_eq!(None, points.next());
+    assert_eq!(None, points.next_back());
+}
+
+#[test]
+fn points_from_both_ends_3d() {
+    let range = Point3D::new(3, 5, 7)..Point3D::new(5, 8, 11);
+    let all = range.clone().points().collect::<Vec<_>>();
+    for split in 0..=all.len() {
+        let mut points = range.clone().points();
+        let mut front = points.by_ref().take(split).collect::<Vec<_>>();
+        let mut back = points.rev().collect::<Vec<_>>();
+        back.reverse();
+        front.extend(back);
+        assert_eq!(all, front);
+    }
+}
+
+#[test]
+fn points_in_reverse_float() {
+    assert_eq!(
+        vec![
+            Point2D::new(1.5, 2.0),
+            Point2D::new(0.5, 2.0),
+            Point2D::new(1.5, 1.0),
+            Point2D::new(0.5, 1.0)
+        ],
+        (Point2D::new(0.5, 1.0)..Point2D::new(2.0, 2.5))
+            .points()
+            .rev()
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn nth_point_2d() {
+    let range = Point2D::new(-3, 4)..Point2D::new(5, 9);
+    let all = range.clone().points().collect::<Vec<_>>();
+    for n in 0..=all.len() {
+        let mut points = range.clone().points();
+        assert_eq!(all.get(n).copied(), points.nth(n));
+        assert_eq!(all.get(n + 1).copied(), points.next());
+        let mut points = range.clone().points();
+        assert_eq!(all.iter().rev().nth(n).copied(), points.nth_back(n));
+        assert_eq!(all.iter().rev().nth(n + 1).copied(), points.next_back());
+    }
+}
+
+#[test]
+fn nth_point_3d() {
+    let range = Point3D::new(3, 5, 7)..Point3D::new(5, 8, 11);
+    let all = range.clone().points().collect::<Vec<_>>();
+    for n in 0..=all.len() {
+        assert_eq!(
+            all[n..].to_vec(),
+            range.clone().points().skip(n).collect::<Vec<_>>()
+        );
+        let mut points = range.clone().points();
+        assert_eq!(all.iter().rev().nth(n).copied(), points.nth_back(n));
+        assert_eq!(all.iter().rev().nth(n + 1).copied(), points.next_back());
+    }
+}
+
+#[test]
+fn skip_large_range() {
+    let mut points = Box3D::new(
+        Point3D::new(0, 0, 0),
+        Point3D::new(100_000, 100_000, 100_000),
+    )
+    .points();
+    assert_eq!(
+        Some(Point3D::new(99_999, 99_999, 99_999)),
+        points.nth(999_999_999_999_999)
+    );
+    assert_eq!(None, points.next());
+}
+
+#[test]
+fn get_and_index_of_points() {
+    let range = Point3D::new(3, 5, 7)..Point3D::new(5, 8, 11);
+    let mut points = range.clone().points();
+    points.next();
+    points.next_back();
+    for (index, point) in range.points().skip(1).take(22).enumerate() {
+        assert_eq!(Some(point), points.get(index));
+        assert_eq!(Some(index), points.index_of(point));
+    }
+    assert_eq!(None, points.get(22));
+    assert_eq!(None, points.index_of(Point3D::new(3, 5, 7)));
+    assert_eq!(None, points.index_of(Point3D::new(4, 7, 10)));
+    assert_eq!(None, points.index_of(Point3D::new(5, 5, 8)));
+}
+
+#[test]
+fn index_of_off_grid_float_point() {
+    let points = (Point2D::new(0.5, 0.0)..Point2D::new(3.0, 2.0)).points();
+    assert_eq!(Some(4), points.index_of(Point2D::new(1.5, 1.0)));
+    assert_eq!(None, points.index_of(Point2D::new(1.0, 1.0)));
+}
+
+#[test]
+fn points_in_column_major_order() {
+    assert_eq!(
+        point_vec![(5, 10), (5, 11), (6, 10), (6, 11), (7, 10), (7, 11)],
+        Box2D::new(Point2D::new(5, 10), Point2D::new(8, 12))
+            .points_in_order(Order2D::YX)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn points_in_every_3d_order() {
+    let range = Point3D::new(3, 5, 7)..Point3D::new(5, 8, 11);
+    let key = |point: &Point3D<i32>, order: Order3D| match order {
+        Order3D::XYZ => (point.z, point.y, point.x),
+        Order3D::XZY => (point.y, point.z, point.x),
+        Order3D::YXZ => (point.z, point.x, point.y),
+        Order3D::YZX => (point.x, point.z, point.y),
+        Order3D::ZXY => (point.y, point.x, point.z),
+        Order3D::ZYX => (point.x, point.y, point.z),
+    };
+    for &order in &[
+        Order3D::XYZ,
+        Order3D::XZY,
+        Order3D::YXZ,
+        Order3D::YZX,
+        Order3D::ZXY,
+        Order3D::ZYX,
+    ] {
+        let mut expected = range.clone().points().collect::<Vec<_>>();
+        expected.sort_by_key(|point| key(point, order));
+        let points = range.clone().points_in_order(order);
+        assert_eq!(expected.len(), points.len());
+        for (index, point) in expected.iter().enumerate() {
+            assert_eq!(Some(index), points.index_of(*point));
+        }
+        assert_eq!(expected, points.collect::<Vec<_>>());
+        expected.reverse();
+        assert_eq!(
+            expected,
+            range
+                .clone()
+                .points_in_order(order)
+                .rev()
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn points_by_step_2d() {
+    let points =
+        Box2D::new(Point2D::new(-5, 10), Point2D::new(5, 17)).points_by(Vector2D::new(4, 3));
+    assert_eq!(9, points.len());
+    assert_eq!(
+        point_vec![
+            (-5, 10),
+            (-1, 10),
+            (3, 10),
+            (-5, 13),
+            (-1, 13),
+            (3, 13),
+            (-5, 16),
+            (-1, 16),
+            (3, 16)
+        ],
+        points.collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn points_by_step_3d() {
+    let range = Point3D::new(0, 0, 0)..Point3D::new(10, 10, 10);
+    let expected = range
+        .clone()
+        .points()
+        .filter(|p| p.x % 3 == 0 && p.y % 5 == 0 && p.z % 2 == 0)
+        .collect::<Vec<_>>();
+    let points = range.points_by(Vector3D::new(3, 5, 2));
+    assert_eq!(expected.len(), points.len());
+    for (index, point) in expected.iter().enumerate() {
+        assert_eq!(Some(index), points.index_of(*point));
+    }
+    assert_eq!(None, points.index_of(Point3D::new(1, 0, 0)));
+    assert_eq!(expected, points.rev().rev().collect::<Vec<_>>());
+}
+
+#[test]
+fn points_by_float_step() {
+    let points =
+        (Point2D::new(0.0, 0.0)..Point2D::new(3.0, 0.5)).points_by(Vector2D::new(0.1, 1.0));
+    assert_eq!(30, points.len());
+    assert!(points.clone().all(|point| point.x < 3.0));
+    assert_eq!(Some(Point2D::new(0.0, 0.0)), points.clone().next());
+    assert_eq!(30, points.count());
+}
+
+#[test]
+fn points_by_float_step_match_get() {
+    let range = PointRange::new(Point3D::new(0.0, -1.0, 0.5)..Point3D::new(1.0, 1.0, 2.0))
+        .step_by(Vector3D::new(0.1, 0.3, 0.7));
+    for &order in &[Order3D::XYZ, Order3D::ZXY] {
+        let points = PointRangeIterator::from_point_range(range.clone(), order);
+        let expected = (0..points.len())
+            .map(|index| points.get(index).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(expected, points.collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn points_by_step_wider_than_range() {
+    assert_eq!(
+        point_vec![(2, 3)],
+        Rect::new(Point2D::new(2, 3), Size2D::new(4, 3))
+            .points_by(Vector2D::new(10, 10))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+#[should_panic(expected = "step must be positive")]
+fn points_by_zero_step() {
+    (Point2D::new(0, 0)..Point2D::new(3, 3)).points_by(Vector2D::new(1, 0));
+}
+
+#[test]
+fn points_in_inclusive_range_2d() {
+    assert_eq!(
+        point_vec![(8, 3), (9, 3), (10, 3), (8, 4), (9, 4), (10, 4)],
+        (Point2D::new(8, 3)..=Point2D::new(10, 4))
+            .points()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        point_vec![(8, 3)],
+        (Point2D::new(8, 3)..=Point2D::new(8, 3))
+            .points()
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn points_in_inclusive_range_3d() {
+    let points = (Point3D::new(3, 5, 7)..=Point3D::new(4, 7, 10)).points();
+    assert_eq!(24, points.len());
+    assert!(points.eq((Point3D::new(3, 5, 7)..Point3D::new(5, 8, 11)).points()));
+}
+
+#[test]
+fn points_in_inclusive_range_to_max() {
+    let points = (Point2D::<u8>::new(0, 254)..=Point2D::new(u8::MAX, u8::MAX)).points();
+    assert_eq!(512, points.len());
+    assert_eq!(Some(Point2D::new(u8::MAX, u8::MAX)), points.clone().last());
+    assert_eq!(Some(511), points.index_of(Point2D::new(u8::MAX, u8::MAX)));
+    assert_eq!(512, points.count());
+    let mut points = (Point3D::<i16>::new(i16::MIN, i16::MAX - 1, i16::MAX)
+        ..=Point3D::new(i16::MAX, i16::MAX, i16::MAX))
+        .points();
+    assert_eq!(65536 * 2, points.len());
+    assert_eq!(
+        Some(Point3D::new(i16::MAX, i16::MAX, i16::MAX)),
+        points.next_back()
+    );
+}
+
+#[test]
+fn points_in_inclusive_range_by_step() {
+    assert_eq!(
+        point_vec![(0, 0), (5, 0), (10, 0), (0, 4), (5, 4), (10, 4)],
+        (Point2D::new(0, 0)..=Point2D::new(10, 7))
+            .points_by(Vector2D::new(5, 4))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![
+            Point2D::new(0.0, 1.0),
+            Point2D::new(0.5, 1.0),
+            Point2D::new(1.0, 1.0)
+        ],
+        (Point2D::new(0.0, 1.0)..=Point2D::new(1.0, 1.0))
+            .points_by(Vector2D::new(0.5, 0.5))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn points_in_inverted_inclusive_range() {
+    assert_eq!(0, (Point2D::new(5, 3)..=Point2D::new(4, 8)).points().len());
+}
+
+#[test]
+fn points_at_bounds_of_small_types() {
+    let points =
+        (Point2D::<u8>::new(250, 0)..=Point2D::new(u8::MAX, 1)).points_by(Vector2D::new(2, 1));
+    assert_eq!(
+        point_vec![(250, 0), (252, 0), (254, 0), (250, 1), (252, 1), (254, 1)],
+        points.collect::<Vec<_>>()
+    );
+    let points = (Point3D::<i8>::new(i8::MIN, i8::MIN, 0)..Point3D::new(i8::MAX, i8::MIN + 1, 1))
+        .points_by(Vector3D::new(127, 1, 1));
+    assert_eq!(
+        point_vec![(i8::MIN, i8::MIN, 0), (-1, i8::MIN, 0), (126, i8::MIN, 0)],
+        points.rev().rev().collect::<Vec<_>>()
+    );
+    let points = (Point2D::<i16>::new(i16::MIN, 0)..=Point2D::new(i16::MAX, 0)).points();
+    assert_eq!(65536, points.len());
+    assert_eq!(Some(40000), points.index_of(Point2D::new(7232, 0)));
+    assert_eq!(Some(Point2D::new(7232, 0)), points.get(40000));
+}
+
+#[test]
+fn points_over_whole_wide_types() {
+    let mut points = (Point2D::<i64>::new(i64::MIN, 0)..Point2D::new(i64::MAX, 1)).points();
+    assert_eq!(usize::MAX, points.len());
+    assert_eq!(Some(Point2D::new(i64::MAX - 1, 0)), points.next_back());
+    let mut points = (Point2D::<u128>::new(u128::MAX - 2, 5)..=Point2D::new(u128::MAX, 5)).points();
+    assert_eq!(Some(Point2D::new(u128::MAX, 5)), points.next_back());
+    assert_eq!(2, points.len());
+}
+
+#[test]
+#[should_panic(expected = "range has more than usize::MAX points")]
+fn points_over_too_many_points() {
+    (Point2D::<u64>::new(0, 0)..=Point2D::new(u64::MAX, 0)).points();
+}
+
+#[test]
+fn serpentine_points_in_box2d() {
+    assert_eq!(
+        point_vec![
+            (5, 10),
+            (6, 10),
+            (7, 10),
+            (7, 11),
+            (6, 11),
+            (5, 11),
+            (5, 12),
+            (6, 12),
+            (7, 12)
+        ],
+        Box2D::new(Point2D::new(5, 10), Point2D::new(8, 13))
+            .serpentine_points()
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn serpentine_points_in_box3d() {
+    assert_eq!(
+        point_vec![
+            (0, 0, 0),
+            (1, 0, 0),
+            (1, 1, 0),
+            (0, 1, 0),
+            (0, 1, 1),
+            (1, 1, 1),
+            (1, 0, 1),
+            (0, 0, 1)
+        ],
+        Box3D::new(Point3D::new(0, 0, 0), Point3D::new(2, 2, 2))
+            .serpentine_points()
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn serpentine_points_are_adjacent() {
+    let points = Box3D::new(Point3D::new(-2i32, 3, 1), Point3D::new(3, 7, 4))
+        .serpentine_points()
+        .collect::<Vec<_>>();
+    assert_eq!(60, points.len());
+    for pair in points.windows(2) {
+        let d = pair[1] - pair[0];
+        assert_eq!(1, d.x.abs() + d.y.abs() + d.z.abs());
+    }
+}
+
+#[test]
+fn serpentine_points_in_order() {
+    let range = PointRange::new(Point2D::new(0, 0)..Point2D::new(2, 3)).serpentine();
+    assert_eq!(
+        point_vec![(0, 0), (0, 1), (0, 2), (1, 2), (1, 1), (1, 0)],
+        PointRangeIterator::from_point_range(range, Order2D::YX).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn serpentine_points_in_inclusive_range_by_step() {
+    let range = PointRange::inclusive(Point2D::new(0, 0)..=Point2D::new(4, 2))
+        .step_by(Vector2D::new(2, 2))
+        .serpentine();
+    assert_eq!(
+        point_vec![(0, 0), (2, 0), (4, 0), (4, 2), (2, 2), (0, 2)],
+        PointRangeIterator::from_point_range(range, Order2D::XY).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn serpentine_points_from_both_ends() {
+    let range = Box3D::new(Point3D::new(0, 0, 0), Point3D::new(3, 3, 3));
+    let mut points = range.serpentine_points().collect::<Vec<_>>();
+    let i = range.serpentine_points();
+    for (index, &point) in points.iter().enumerate() {
+        assert_eq!(Some(point), i.get(index));
+        assert_eq!(Some(index), i.index_of(point));
+    }
+    points.reverse();
+    assert_eq!(points, range.serpentine_points().rev().collect::<Vec<_>>());
+}
+
+struct Row(i32);
+
+impl ToPointRange for Row {
+    type Point = Point2D<i32>;
+
+    fn to_point_range(self) -> ops::Range<Self::Point> {
+        Point2D::new(0, self.0)..Point2D::new(3, self.0 + 1)
+    }
+}
+
+#[test]
+fn points_of_custom_point_range() {
+    assert_eq!(
+        point_vec![(0, 4), (1, 4), (2, 4)],
+        Row(4).points().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn next_point_of_range() {
+    let range = Point2D::new(0, 0)..Point2D::new(2, 2);
+    let mut point = range.start;
+    let mut points = Vec::new();
+    while let Some(p) = point.next(&range) {
+        points.push(p);
+    }
+    assert_eq!(point_vec![(0, 0), (1, 0), (0, 1), (1, 1)], points);
+    assert_eq!(range.end, point);
+}
@@ -0,0 +1,128 @@
+use euclid::{Box2D, Box3D};
+use euclid_ext::RayVoxels;
+
+enum Space {}
+
+type Point2D<T> = euclid::Point2D<T, Space>;
+
+type Point3D<T> = euclid::Point3D<T, Space>;
+
+type Vector2D<T> = euclid::Vector2D<T, Space>;
+
+type Vector3D<T> = euclid::Vector3D<T, Space>;
+
+#[test]
+fn ray_voxels_2d() {
+    let bounds = Box2D::new(Point2D::new(0, 0), Point2D::new(3, 3));
+    let voxels = Point2D::new(0.5, 0.5)
+        .ray_voxels(Vector2D::new(1.0, 0.5), bounds)
+        .map(|v| (v.voxel, v.t, v.face))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            (Point2D::new(0, 0), 0.0, None),
+            (Point2D::new(1, 0), 0.5, Some(Vector2D::new(-1, 0))),
+            (Point2D::new(1, 1), 1.0, Some(Vector2D::new(0, -1))),
+            (Point2D::new(2, 1), 1.5, Some(Vector2D::new(-1, 0)))
+        ],
+        voxels
+    );
+}
+
+#[test]
+fn ray_voxels_in_negative_direction() {
+    let bounds = Box2D::new(Point2D::new(-4, -4), Point2D::new(4, 4));
+    assert_eq!(
+        vec![
+            Point2D::new(3, -1),
+            Point2D::new(2, -1),
+            Point2D::new(1, -1),
+            Point2D::new(0, -1),
+            Point2D::new(-1, -1),
+            Point2D::new(-2, -1),
+            Point2D::new(-3, -1),
+            Point2D::new(-4, -1)
+        ],
+        Point2D::new(10.0, -0.5)
+            .ray_voxels(Vector2D::new(-2.0, 0.0), bounds)
+            .map(|v| v.voxel)
+            .collect::<Vec<_>>()
+    );
+    let first = Point2D::new(10.0, -0.5)
+        .ray_voxels(Vector2D::new(-2.0, 0.0), bounds)
+        .next()
+        .unwrap();
+    assert_eq!(3.0, first.t);
+    assert_eq!(Some(Vector2D::new(1, 0)), first.face);
+}
+
+#[test]
+fn ray_voxels_3d_are_connected() {
+    let bounds = Box3D::new(Point3D::new(-5, -5, -5), Point3D::new(6, 7, 8));
+    let origin = Point3D::new(-8.3, 1.2, -0.7);
+    let direction = Vector3D::new(1.3, -0.37, 0.9);
+    let voxels = origin.ray_voxels(direction, bounds).collect::<Vec<_>>();
+    assert!(voxels.len() > 10);
+    assert_eq!(Some(Vector3D::new(-1, 0, 0)), voxels[0].face);
+    assert_eq!(-5, voxels[0].voxel.x);
+    for pair in voxels.windows(2) {
+        assert!(pair[0].t < pair[1].t);
+        assert_eq!(Some(pair[0].voxel - pair[1].voxel), pair[1].face);
+        // The ray is inside each voxel between its entry and exit.
+        let p = origin + direction * ((pair[0].t + pair[1].t) / 2.0);
+        let voxel = pair[0].voxel;
+        assert_eq!(voxel, p.floor().cast());
+    }
+    let last = voxels.last().unwrap().voxel;
+    assert!(last.x == 5 || last.y == -5 || last.z == 7);
+}
+
+#[test]
+fn ray_voxels_missing_bounds() {
+    let bounds = Box3D::new(Point3D::new(0, 0, 0), Point3D::new(4, 4, 4));
+    assert_eq!(
+        0,
+        Point3D::new(-1.0, 0.5, 0.5)
+            .ray_voxels(Vector3D::new(-1.0, 0.0, 0.0), bounds)
+            .count()
+    );
+    assert_eq!(
+        0,
+        Point3D::new(-1.0, 5.5, 0.5)
+            .ray_voxels(Vector3D::new(1.0, 0.0, 0.0), bounds)
+            .count()
+    );
+    assert_eq!(
+        0,
+        Point3D::new(0.5, 0.5, 0.5)
+            .ray_voxels(
+                Vector3D::new(1.0, 0.0, 0.0),
+                Box3D::new(Point3D::new(0, 0, 0), Point3D::new(4, 0, 4))
+            )
+            .count()
+    );
+}
+
+#[test]
+fn ray_voxels_without_direction() {
+    let bounds = Box3D::new(Point3D::new(0, 0, 0), Point3D::new(4, 4, 4));
+    assert_eq!(
+        vec![Point3D::new(2, 3, 1)],
+        Point3D::new(2.5, 3.5, 1.5)
+            .ray_voxels(Vector3D::new(0.0, 0.0, 0.0), bounds)
+            .map(|v| v.voxel)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn ray_voxels_within_inclusive_range() {
+    let bounds = Point2D::new(0, 0)..=Point2D::new(2, 0);
+    assert_eq!(
+        vec![Point2D::new(0, 0), Point2D::new(1, 0), Point2D::new(2, 0)],
+        Point2D::new(-1.0, 0.5)
+            .ray_voxels(Vector2D::new(1.0, 0.0), bounds)
+            .map(|v| v.voxel)
+            .collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,369 @@
+use euclid::{Box2D, Size2D};
+use euclid_ext::{Ball, Circle, Disk, Ellipse, FillRule, Points, Polygon, ShapePoints, Triangle};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+enum Space {}
+
+type Point2D = euclid::Point2D<i32, Space>;
+type Point3D = euclid::Point3D<i32, Space>;
+
+fn midpoint_circle(center: Point2D, radius: i32) -> Vec<Point2D> {
+    let mut points = BTreeSet::new();
+    let (mut x, mut y, mut d) = (0, radius, 1 - radius);
+    while x <= y {
+        for &(a, b) in &[(x, y), (y, x)] {
+            for &(sa, sb) in &[(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+                points.insert((center.y + sb * b, center.x + sa * a));
+            }
+        }
+        x += 1;
+        if d < 0 {
+            d += 2 * x + 1;
+        } else {
+            y -= 1;
+            d += 2 * (x - y) + 1;
+        }
+    }
+    points
+        .into_iter()
+        .map(|(y, x)| Point2D::new(x, y))
+        .collect()
+}
+
+#[test]
+fn circle_points_match_midpoint_algorithm() {
+    for radius in 0..100 {
+        let center = Point2D::new(-7, 12);
+        let points = Circle::new(center, radius).shape_points();
+        assert_eq!(midpoint_circle(center, radius).len(), points.len());
+        assert_eq!(midpoint_circle(center, radius), points.collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn disk_points_fill_circle() {
+    for radius in 0..100 {
+        let center = Point2D::new(5, -3);
+        let circle = midpoint_circle(center, radius);
+        let mut expected = Vec::new();
+        for y in center.y - radius..=center.y + radius {
+            let xs = circle.iter().filter(|p| p.y == y).map(|p| p.x);
+            let (low, high) = (xs.clone().min().unwrap(), xs.max().unwrap());
+            expected.extend((low..=high).map(|x| Point2D::new(x, y)));
+        }
+        let points = Disk::new(center, radius).shape_points();
+        assert_eq!(expected.len(), points.len());
+        assert_eq!(expected, points.collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn ellipse_points() {
+    let center = Point2D::new(1, 2);
+    let points = Ellipse::new(center, Size2D::new(7, 3))
+        .shape_points()
+        .collect::<Vec<_>>();
+    let expected = Box2D::new(Point2D::new(-6, -1), Point2D::new(9, 6))
+        .points()
+        .filter(|p| {
+            let (x, y) = (2 * (p.x - center.x), 2 * (p.y - center.y));
+            (x * x) as f64 / 225.0 + (y * y) as f64 / 49.0 <= 1.0
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(expected, points);
+}
+
+#[test]
+fn ellipse_points_with_zero_radius() {
+    assert_eq!(
+        vec![Point2D::new(0, -1), Point2D::new(0, 0), Point2D::new(0, 1)],
+        Ellipse::new(Point2D::new(0, 0), Size2D::new(0, 1))
+            .shape_points()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![Point2D::new(0, 0), Point2D::new(1, 0), Point2D::new(2, 0)],
+        Ellipse::new(Point2D::new(1, 0), Size2D::new(1, 0))
+            .shape_points()
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn ball_points() {
+    let center = Point3D::new(2, -4, 6);
+    for radius in 0..20 {
+        let points = Ball::new(center, radius).shape_points();
+        assert_eq!(points.len(), points.clone().count());
+        let points = points.collect::<Vec<_>>();
+        let slice = points
+            .iter()
+            .filter(|p| p.z == center.z)
+            .map(|p| p.xy())
+            .collect::<Vec<_>>();
+        let disk = Disk::new(center.xy(), radius)
+            .shape_points()
+            .collect::<Vec<_>>();
+        assert_eq!(disk, slice);
+        let set = points.iter().collect::<HashSet<_>>();
+        assert_eq!(points.len(), set.len());
+        for p in &points {
+            let d = *p - center;
+            for &q in &[
+                Point3D::new(d.y, d.z, d.x),
+                Point3D::new(-d.x, d.y, -d.z),
+                Point3D::new(d.z, -d.x, d.y),
+            ] {
+                assert!(set.contains(&(q + center.to_vector())));
+            }
+        }
+    }
+}
+
+#[test]
+fn shape_points_are_in_row_major_order() {
+    let points = Ball::new(Point3D::new(0, 0, 0), 5)
+        .shape_points()
+        .collect::<Vec<_>>();
+    for pair in points.windows(2) {
+        assert!((pair[0].z, pair[0].y, pair[0].x) < (pair[1].z, pair[1].y, pair[1].x));
+    }
+}
+
+#[test]
+fn shape_points_within_bounds() {
+    let circle = Circle::new(Point2D::new(3, 4), 9);
+    let bounds = Box2D::new(Point2D::new(0, -10), Point2D::new(10, 6));
+    let points = circle.shape_points_within(bounds);
+    let expected = circle
+        .shape_points()
+        .filter(|p| bounds.contains(*p))
+        .collect::<Vec<_>>();
+    assert_eq!(expected.len(), points.len());
+    assert_eq!(expected, points.collect::<Vec<_>>());
+    let disjoint = Box2D::new(Point2D::new(20, 20), Point2D::new(30, 30));
+    assert_eq!(0, circle.shape_points_within(disjoint).count());
+}
+
+#[test]
+fn shape_points_with_negative_radius() {
+    assert_eq!(0, Circle::new(Point2D::new(0, 0), -1).shape_points().len());
+    assert_eq!(0, Disk::new(Point2D::new(0, 0), -2).shape_points().len());
+    assert_eq!(
+        0,
+        Ellipse::new(Point2D::new(0, 0), Size2D::new(-1, 3))
+            .shape_points()
+            .len()
+    );
+    assert_eq!(0, Ball::new(Point3D::new(0, 0, 0), -1).shape_points().len());
+}
+
+#[test]
+fn shape_points_at_type_bounds() {
+    let ellipse = Ellipse::new(Point2D::new(0, 0), Size2D::new(i32::MAX, i32::MAX));
+    let bounds = Box2D::new(Point2D::new(-2, -2), Point2D::new(3, 3));
+    assert_eq!(
+        bounds.points().collect::<Vec<_>>(),
+        ellipse.shape_points_within(bounds).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![Point2D::new(i32::MAX - 2, 0), Point2D::new(i32::MAX - 1, 0)],
+        ellipse
+            .shape_points_within(Point2D::new(i32::MAX - 2, 0)..=Point2D::new(i32::MAX, 0))
+            .collect::<Vec<_>>()
+    );
+    let disk = Disk::new(Point2D::new(i32::MAX, i32::MIN), 1);
+    assert_eq!(1, disk.shape_points().len());
+    assert_eq!(
+        vec![Point2D::new(i32::MAX - 1, i32::MIN)],
+        disk.shape_points().collect::<Vec<_>>()
+    );
+    let ball = Ball::new(Point3D::new(i32::MIN, i32::MIN, i32::MIN), i32::MAX);
+    let bounds = Point3D::new(i32::MIN, i32::MIN, i32::MIN)
+        ..Point3D::new(i32::MIN + 2, i32::MIN + 2, i32::MIN + 2);
+    assert_eq!(
+        bounds.clone().points().collect::<Vec<_>>(),
+        ball.shape_points_within(bounds).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn triangle_points_at_type_bounds() {
+    let triangle = Triangle::new(
+        Point2D::new(i32::MIN, i32::MIN),
+        Point2D::new(i32::MAX, i32::MIN),
+        Point2D::new(i32::MIN, i32::MAX),
+    );
+    // The long edge runs along `x + y = -1`.
+    let bounds = Box2D::new(Point2D::new(-3, -3), Point2D::new(3, 3));
+    let points = triangle.shape_points_within(bounds).collect::<HashSet<_>>();
+    for p in bounds.points() {
+        if p.x + p.y < -1 {
+            assert!(points.contains(&p));
+        } else if p.x + p.y > -1 {
+            assert!(!points.contains(&p));
+        }
+    }
+    let corner = Box2D::new(
+        Point2D::new(i32::MAX - 2, i32::MIN),
+        Point2D::new(i32::MAX, i32::MIN + 1),
+    );
+    assert_eq!(
+        corner.points().collect::<Vec<_>>(),
+        triangle.shape_points_within(corner).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn triangles_sharing_edges_cover_cells_once() {
+    let (count, size) = (6, 7);
+    let mut seed = 12345u32;
+    let mut jitter = || {
+        seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        (seed >> 16) as i32 % 3 - 1
+    };
+    let mut vertices = Vec::new();
+    for j in 0..=count {
+        for i in 0..=count {
+            let x = if i == 0 || i == count { 0 } else { jitter() };
+            let y = if j == 0 || j == count { 0 } else { jitter() };
+            vertices.push(Point2D::new(i * size + x, j * size + y));
+        }
+    }
+    let vertex = |i: i32, j: i32| vertices[(j * (count + 1) + i) as usize];
+    let mut covered = HashMap::new();
+    for j in 0..count {
+        for i in 0..count {
+            let (a, b, c, d) = (
+                vertex(i, j),
+                vertex(i + 1, j),
+                vertex(i + 1, j + 1),
+                vertex(i, j + 1),
+            );
+            let triangles = if (i + j) % 2 == 0 {
+                [Triangle::new(a, b, c), Triangle::new(a, c, d)]
+            } else {
+                [Triangle::new(a, b, d), Triangle::new(d, b, c)]
+            };
+            for triangle in &triangles {
+                for point in triangle.shape_points() {
+                    *covered.entry(point).or_insert(0) += 1;
+                }
+            }
+        }
+    }
+    let square = Box2D::new(Point2D::new(0, 0), Point2D::new(count * size, count * size));
+    assert_eq!(square.area() as usize, covered.len());
+    for point in square.points() {
+        assert_eq!(Some(&1), covered.get(&point));
+    }
+}
+
+#[test]
+fn polygon_points_match_triangle_fan() {
+    let vertices = vec![
+        Point2D::new(3, -10),
+        Point2D::new(11, -4),
+        Point2D::new(12, 5),
+        Point2D::new(4, 13),
+        Point2D::new(-6, 9),
+        Point2D::new(-9, -1),
+    ];
+    let mut fan = Vec::new();
+    for pair in vertices[1..].windows(2) {
+        fan.extend(Triangle::new(vertices[0], pair[0], pair[1]).shape_points());
+    }
+    fan.sort_by_key(|p| (p.y, p.x));
+    let points = Polygon::new(vertices.clone(), FillRule::NonZero).shape_points();
+    assert_eq!(fan.len(), points.len());
+    assert_eq!(fan, points.collect::<Vec<_>>());
+    let reversed = vertices.into_iter().rev().collect();
+    assert_eq!(
+        fan,
+        Polygon::new(reversed, FillRule::EvenOdd)
+            .shape_points()
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn polygon_points_with_fill_rules() {
+    let star = vec![
+        Point2D::new(0, -20),
+        Point2D::new(12, 16),
+        Point2D::new(-19, -6),
+        Point2D::new(19, -6),
+        Point2D::new(-12, 16),
+    ];
+    let non_zero = Polygon::new(star.clone(), FillRule::NonZero)
+        .shape_points()
+        .collect::<HashSet<_>>();
+    let even_odd = Polygon::new(star.clone(), FillRule::EvenOdd)
+        .shape_points()
+        .collect::<HashSet<_>>();
+    assert!(even_odd.is_subset(&non_zero));
+    assert!(non_zero.contains(&Point2D::new(0, 0)));
+    assert!(!even_odd.contains(&Point2D::new(0, 0)));
+    let points = Polygon::new(star, FillRule::EvenOdd).shape_points();
+    assert_eq!(points.len(), points.clone().count());
+}
+
+#[test]
+fn polygon_points_within_bounds() {
+    let polygon = Polygon::new(
+        vec![
+            Point2D::new(-5, -5),
+            Point2D::new(20, 0),
+            Point2D::new(2, 18),
+        ],
+        FillRule::NonZero,
+    );
+    let bounds = Box2D::new(Point2D::new(0, 0), Point2D::new(10, 10));
+    let expected = polygon
+        .clone()
+        .shape_points()
+        .filter(|p| bounds.contains(*p))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        expected,
+        polygon.shape_points_within(bounds).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn degenerate_polygon_points() {
+    assert_eq!(
+        0,
+        Polygon::<Point2D>::new(Vec::new(), FillRule::NonZero)
+            .shape_points()
+            .len()
+    );
+    assert_eq!(
+        0,
+        Triangle::new(Point2D::new(0, 0), Point2D::new(5, 5), Point2D::new(10, 10))
+            .shape_points()
+            .len()
+    );
+}
+
+#[test]
+fn shape_points_within_inclusive_range() {
+    let disk = Disk::new(Point2D::new(0, 0), 3);
+    let bounds = Point2D::new(-1, -1)..=Point2D::new(1, 1);
+    assert_eq!(
+        bounds.clone().points().collect::<Vec<_>>(),
+        disk.shape_points_within(bounds).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn shape_points_len_while_iterating() {
+    let disk = Disk::new(Point2D::new(0, 0), 4);
+    let total = disk.shape_points().count();
+    let mut points = disk.shape_points();
+    points.nth(6);
+    assert_eq!(total - 7, points.len());
+    points.nth(2);
+    assert_eq!(total - 10, points.len());
+    assert_eq!(total - 10, points.count());
+}
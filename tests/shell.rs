@@ -0,0 +1,134 @@
+use euclid::{Box2D, Box3D, Rect, Size2D};
+use euclid_ext::{Points, ShellPoints};
+
+enum Space {}
+
+type Point2D<T> = euclid::Point2D<T, Space>;
+
+type Point3D<T> = euclid::Point3D<T, Space>;
+
+fn filtered_points_2d(range: Box2D<i32, Space>, thickness: i32) -> Vec<Point2D<i32>> {
+    range
+        .points()
+        .filter(|p| {
+            p.x < range.min.x + thickness
+                || p.x >= range.max.x - thickness
+                || p.y < range.min.y + thickness
+                || p.y >= range.max.y - thickness
+        })
+        .collect()
+}
+
+#[test]
+fn shell_points_of_box2d() {
+    let range = Box2D::new(Point2D::new(-3, 2), Point2D::new(7, 9));
+    for thickness in 0..6 {
+        let points = range.shell_points(thickness as usize);
+        assert_eq!(filtered_points_2d(range, thickness).len(), points.len());
+        assert_eq!(
+            filtered_points_2d(range, thickness),
+            points.collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn shell_points_of_rect() {
+    assert_eq!(
+        vec![
+            Point2D::new(1, 1),
+            Point2D::new(2, 1),
+            Point2D::new(1, 2),
+            Point2D::new(2, 2)
+        ],
+        Rect::new(Point2D::new(1, 1), Size2D::new(2, 2))
+            .shell_points(1)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn shell_points_of_box3d() {
+    let range = Box3D::new(Point3D::new(0, 0, 0), Point3D::new(6, 5, 7));
+    for thickness in 0..4 {
+        let expected = range
+            .points()
+            .filter(|p| {
+                p.x < thickness
+                    || p.x >= 6 - thickness
+                    || p.y < thickness
+                    || p.y >= 5 - thickness
+                    || p.z < thickness
+                    || p.z >= 7 - thickness
+            })
+            .collect::<Vec<_>>();
+        let points = range.shell_points(thickness as usize);
+        assert_eq!(expected.len(), points.len());
+        assert_eq!(expected, points.collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn shell_points_in_reverse() {
+    let range = Box3D::new(Point3D::new(0, 0, 0), Point3D::new(4, 4, 4));
+    let mut points = range.shell_points(1).collect::<Vec<_>>();
+    points.reverse();
+    assert_eq!(points, range.shell_points(1).rev().collect::<Vec<_>>());
+}
+
+#[test]
+fn shell_points_from_both_ends() {
+    let range = Box2D::new(Point2D::new(0, 0), Point2D::new(5, 5));
+    let mut i = range.shell_points(2);
+    let mut points = Vec::new();
+    while let Some(front) = i.next() {
+        points.push(front);
+        if let Some(back) = i.next_back() {
+            points.push(back);
+        }
+    }
+    points.sort_by_key(|p| (p.y, p.x));
+    assert_eq!(filtered_points_2d(range, 2), points);
+}
+
+#[test]
+fn shell_points_at_bounds_of_type() {
+    let range = Box2D::new(Point2D::new(u8::MAX - 3, 0), Point2D::new(u8::MAX, 3));
+    assert_eq!(
+        vec![
+            Point2D::new(252, 0),
+            Point2D::new(253, 0),
+            Point2D::new(254, 0),
+            Point2D::new(252, 1),
+            Point2D::new(254, 1),
+            Point2D::new(252, 2),
+            Point2D::new(253, 2),
+            Point2D::new(254, 2)
+        ],
+        range.shell_points(1).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn shell_points_of_empty_box() {
+    assert_eq!(
+        0,
+        Box2D::new(Point2D::new(0, 0), Point2D::new(0, 5))
+            .shell_points(1)
+            .count()
+    );
+}
+
+#[test]
+fn shell_points_of_inclusive_range() {
+    let range = Point2D::new(u8::MAX - 3, 0)..=Point2D::new(u8::MAX, 3);
+    let expected = range
+        .clone()
+        .points()
+        .filter(|p| p.x == u8::MAX - 3 || p.x == u8::MAX || p.y == 0 || p.y == 3)
+        .collect::<Vec<_>>();
+    let points = range.clone().shell_points(1);
+    assert_eq!(12, points.len());
+    assert_eq!(expected, points.collect::<Vec<_>>());
+    assert_eq!(16, range.shell_points(2).count());
+}
@@ -0,0 +1,178 @@
+use euclid::{Box2D, Box3D, Size2D, Size3D};
+use euclid_ext::{Grid2D, Points, SparseGrid2D, SparseGrid3D};
+
+enum Space {}
+
+type Point2D = euclid::Point2D<i32, Space>;
+type Point3D = euclid::Point3D<i32, Space>;
+
+#[test]
+fn sparse_grid_2d_chunks_with_negative_points() {
+    let mut grid = SparseGrid2D::new(Size2D::new(3, 2), 0);
+    assert!(grid.is_empty());
+    assert_eq!(Box2D::zero(), grid.bounds());
+    grid[Point2D::new(-1, -1)] = 1;
+    grid[Point2D::new(-3, -2)] = 2;
+    grid[Point2D::new(0, 0)] = 3;
+    assert_eq!(2, grid.chunk_count());
+    assert_eq!(12, grid.len());
+    assert_eq!(
+        Box2D::new(Point2D::new(-3, -2), Point2D::new(3, 2)),
+        grid.bounds()
+    );
+    assert_eq!(
+        Box2D::new(Point2D::new(-3, -2), Point2D::new(0, 0)),
+        grid.chunk_bounds(Point2D::new(-1, -1))
+    );
+    assert_eq!(Some(&0), grid.get(Point2D::new(2, 1)));
+    assert_eq!(None, grid.get(Point2D::new(3, 1)));
+    assert_eq!(0, grid[Point2D::new(3, 1)]);
+    assert_eq!(2, grid.chunk_count());
+    assert_eq!(Some(&1), grid.get(Point2D::new(-1, -1)));
+    assert_eq!(Some(&2), grid.get(Point2D::new(-3, -2)));
+    assert_eq!(Some(&3), grid.get(Point2D::new(0, 0)));
+}
+
+#[test]
+fn sparse_grid_2d_matches_dense_grid() {
+    let bounds = Box2D::new(Point2D::new(-7, -5), Point2D::new(9, 6));
+    let value = |p: Point2D| p.x * 100 + p.y;
+    let dense = Grid2D::from_fn(bounds, value);
+    let mut sparse = SparseGrid2D::new(Size2D::new(4, 4), i32::MIN);
+    for (point, &cell) in &dense {
+        sparse.set(point, cell);
+    }
+    assert_eq!(
+        Box2D::new(Point2D::new(-8, -8), Point2D::new(12, 8)),
+        sparse.bounds()
+    );
+    for point in sparse.bounds().points() {
+        let expected = if dense.contains(point) {
+            value(point)
+        } else {
+            i32::MIN
+        };
+        assert_eq!(expected, sparse[point]);
+    }
+    let cells = sparse
+        .iter()
+        .filter(|&(_, &cell)| cell != i32::MIN)
+        .map(|(point, &cell)| (point, cell))
+        .collect::<Vec<_>>();
+    assert_eq!(dense.len(), cells.len());
+    assert!(cells.iter().all(|&(point, cell)| value(point) == cell));
+}
+
+#[test]
+fn sparse_grid_2d_iterates_chunks_in_order() {
+    let mut grid = SparseGrid2D::new(Size2D::new(2, 2), false);
+    for &(x, y) in &[(5, 5), (-3, 4), (0, -9), (8, -9), (1, 4)] {
+        grid[Point2D::new(x, y)] = true;
+    }
+    let starts = grid
+        .chunks()
+        .map(|chunk| chunk.bounds().min)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            Point2D::new(0, -10),
+            Point2D::new(8, -10),
+            Point2D::new(-4, 4),
+            Point2D::new(0, 4),
+            Point2D::new(4, 4),
+        ],
+        starts
+    );
+    let points = grid.points().collect::<Vec<_>>();
+    assert_eq!(grid.len(), points.len());
+    for (chunk, points) in grid.chunks().zip(points.chunks(4)) {
+        assert_eq!(chunk.bounds().points().collect::<Vec<_>>(), points);
+    }
+    for (_, cell) in grid.iter_mut() {
+        *cell = !*cell;
+    }
+    assert_eq!(15, grid.iter().filter(|&(_, &cell)| cell).count());
+    assert!(grid.remove_chunk(Point2D::new(9, -10)).is_some());
+    assert_eq!(None, grid.remove_chunk(Point2D::new(9, -10)));
+    assert_eq!(4, grid.chunk_count());
+    grid.clear();
+    assert!(grid.is_empty());
+}
+
+#[test]
+fn sparse_grid_3d_allocates_on_write() {
+    let mut grid = SparseGrid3D::new(Size3D::new(4, 2, 3), 0u32);
+    assert_eq!(None, grid.get_mut(Point3D::new(1, 1, 1)));
+    assert!(grid.is_empty());
+    *grid.get_or_insert_mut(Point3D::new(-1, 2, 5)) += 7;
+    assert_eq!(
+        Box3D::new(Point3D::new(-4, 2, 3), Point3D::new(0, 4, 6)),
+        grid.bounds()
+    );
+    assert_eq!(
+        grid.bounds().points().collect::<Vec<_>>(),
+        grid.points().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![(Point3D::new(-1, 2, 5), &7)],
+        grid.iter()
+            .filter(|&(_, &cell)| cell != 0)
+            .collect::<Vec<_>>()
+    );
+    let copy = grid.clone();
+    grid.set(Point3D::new(-1, 2, 5), 7);
+    assert_eq!(copy, grid);
+}
+
+#[test]
+fn sparse_grid_at_limits_of_i32() {
+    let mut grid = SparseGrid2D::new(Size2D::new(16, 16), 0);
+    grid[Point2D::new(i32::MIN, i32::MAX - 1)] = 1;
+    assert_eq!(
+        Box2D::new(
+            Point2D::new(i32::MIN, i32::MAX - 15),
+            Point2D::new(i32::MIN + 16, i32::MAX)
+        ),
+        grid.bounds()
+    );
+    assert_eq!(Some(&1), grid.get(Point2D::new(i32::MIN, i32::MAX - 1)));
+}
+
+#[test]
+fn sparse_grid_at_limits_of_i32_with_uneven_chunks() {
+    let mut grid = SparseGrid2D::new(Size2D::new(3, 5), 0);
+    let (min, max) = (
+        Point2D::new(i32::MIN, i32::MIN),
+        Point2D::new(i32::MAX - 1, i32::MAX - 1),
+    );
+    grid.set(min, 1);
+    grid.set(max, 2);
+    assert_eq!(2, grid.chunk_count());
+    assert_eq!(
+        Box2D::new(min, Point2D::new(i32::MIN + 2, i32::MIN + 3)),
+        grid.chunk_bounds(min)
+    );
+    assert_eq!(
+        Box2D::new(
+            Point2D::new(i32::MAX - 1, i32::MAX - 2),
+            Point2D::new(i32::MAX, i32::MAX)
+        ),
+        grid.chunk_bounds(max)
+    );
+    assert_eq!(Some(&1), grid.get(min));
+    assert_eq!(Some(&2), grid.get(max));
+    assert_eq!(Some(&0), grid.get(Point2D::new(i32::MIN + 1, i32::MIN + 2)));
+    assert_eq!(None, grid.get(Point2D::new(i32::MIN + 2, i32::MIN)));
+}
+
+#[test]
+#[should_panic(expected = "cells at i32::MAX are outside every chunk")]
+fn sparse_grid_at_maximum_of_i32() {
+    SparseGrid3D::new(Size3D::new(4, 4, 4), 0).set(Point3D::new(0, i32::MAX, 0), 1);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be positive")]
+fn sparse_grid_with_empty_chunks() {
+    SparseGrid3D::<u8, Space>::new(Size3D::new(4, 0, 4), 0);
+}
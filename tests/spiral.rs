@@ -0,0 +1,175 @@
+use euclid::{Box2D, Rect, Size2D};
+use euclid_ext::{Points, SpiralPoints};
+use std::collections::HashSet;
+
+enum Space {}
+
+type Point2D<T> = euclid::Point2D<T, Space>;
+
+fn radius(center: Point2D<i32>, point: Point2D<i32>) -> i32 {
+    (point.x - center.x).abs().max((point.y - center.y).abs())
+}
+
+#[test]
+fn spiral_points_in_rings() {
+    let center = Point2D::new(-3, 7);
+    let points = center.spiral_points(Some(3)).collect::<Vec<_>>();
+    assert_eq!(49, points.len());
+    assert_eq!(49, points.iter().collect::<HashSet<_>>().len());
+    assert_eq!(center, points[0]);
+    for pair in points.windows(2) {
+        assert!(radius(center, pair[0]) <= radius(center, pair[1]));
+        let d = pair[1] - pair[0];
+        assert_eq!(1, d.x.abs() + d.y.abs());
+    }
+    assert!(points.iter().all(|&p| radius(center, p) <= 3));
+}
+
+#[test]
+fn spiral_points_of_first_ring() {
+    assert_eq!(
+        vec![
+            Point2D::new(0, 0),
+            Point2D::new(1, 0),
+            Point2D::new(1, 1),
+            Point2D::new(0, 1),
+            Point2D::new(-1, 1),
+            Point2D::new(-1, 0),
+            Point2D::new(-1, -1),
+            Point2D::new(0, -1),
+            Point2D::new(1, -1)
+        ],
+        Point2D::new(0, 0)
+            .spiral_points(Some(1))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn spiral_points_with_zero_radius() {
+    assert_eq!(
+        vec![Point2D::new(4, 5)],
+        Point2D::new(4, 5)
+            .spiral_points(Some(0))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn spiral_points_without_max_radius() {
+    let mut i = Point2D::new(0, 0).spiral_points(None);
+    assert_eq!((usize::MAX, None), i.size_hint());
+    assert_eq!(Some(Point2D::new(500, -500)), i.nth(1001 * 1001 - 1));
+}
+
+#[test]
+fn spiral_points_within_bounds() {
+    let bounds = Box2D::new(Point2D::new(2, -1), Point2D::new(9, 4));
+    for &center in &[
+        Point2D::new(4, 1),
+        Point2D::new(2, -1),
+        Point2D::new(-20, 30),
+    ] {
+        let points = center
+            .spiral_points_within(bounds, None)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bounds.points().collect::<HashSet<_>>(),
+            points.iter().copied().collect::<HashSet<_>>()
+        );
+        assert_eq!(bounds.points().len(), points.len());
+        for pair in points.windows(2) {
+            assert!(radius(center, pair[0]) <= radius(center, pair[1]));
+        }
+    }
+}
+
+#[test]
+fn spiral_points_within_bounds_and_radius() {
+    let bounds = Rect::new(Point2D::new(0, 0), Size2D::new(10, 10));
+    let i = Point2D::new(1, 1).spiral_points_within(bounds, Some(2));
+    assert_eq!((16, Some(16)), i.size_hint());
+    assert_eq!(
+        Box2D::new(Point2D::new(0, 0), Point2D::new(4, 4))
+            .points()
+            .collect::<HashSet<_>>(),
+        i.collect::<HashSet<_>>()
+    );
+}
+
+#[test]
+fn spiral_points_within_empty_bounds() {
+    assert_eq!(
+        0,
+        Point2D::new(0, 0)
+            .spiral_points_within(Box2D::new(Point2D::new(0, 0), Point2D::new(0, 5)), None)
+            .count()
+    );
+}
+
+#[test]
+fn spiral_points_at_bounds_of_unsigned_type() {
+    let bounds = Box2D::new(Point2D::new(0u8, 0), Point2D::new(u8::MAX, 2));
+    let points = Point2D::new(0u8, 0)
+        .spiral_points_within(bounds, Some(2))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            Point2D::new(0, 0),
+            Point2D::new(1, 0),
+            Point2D::new(1, 1),
+            Point2D::new(0, 1),
+            Point2D::new(2, 0),
+            Point2D::new(2, 1)
+        ],
+        points
+    );
+}
+
+#[test]
+fn spiral_points_of_floats() {
+    let bounds = Box2D::new(Point2D::new(0.0, 0.0), Point2D::new(2.0, 1.0));
+    assert_eq!(
+        vec![Point2D::new(0.5, 0.5), Point2D::new(1.5, 0.5)],
+        Point2D::new(0.5, 0.5)
+            .spiral_points_within(bounds, None)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn spiral_points_out_of_type_range() {
+    let i = Point2D::new(0u32, 0).spiral_points(Some(3));
+    assert_eq!((16, Some(16)), i.size_hint());
+    let mut points = i.collect::<Vec<_>>();
+    points.sort_by_key(|p| (p.y, p.x));
+    assert_eq!(
+        (Point2D::new(0, 0)..=Point2D::new(3, 3))
+            .points()
+            .collect::<Vec<_>>(),
+        points
+    );
+    let i = Point2D::new(i32::MAX, 0).spiral_points(Some(2));
+    assert_eq!((15, Some(15)), i.size_hint());
+    let mut points = i.collect::<Vec<_>>();
+    points.sort_by_key(|p| (p.y, p.x));
+    assert_eq!(
+        (Point2D::new(i32::MAX - 2, -2)..=Point2D::new(i32::MAX, 2))
+            .points()
+            .collect::<Vec<_>>(),
+        points
+    );
+    let mut i = Point2D::new(1u8, 1).spiral_points(None);
+    assert_eq!((65536, Some(65536)), i.size_hint());
+    assert_eq!(65536, i.by_ref().count());
+    assert_eq!((0, Some(0)), i.size_hint());
+}
+
+#[test]
+fn spiral_points_within_inclusive_range() {
+    let bounds = Point2D::new(i32::MAX - 1, i32::MAX - 1)..=Point2D::new(i32::MAX, i32::MAX);
+    let points = Point2D::new(i32::MAX, i32::MAX)
+        .spiral_points_within(bounds.clone(), None)
+        .collect::<HashSet<_>>();
+    assert_eq!(bounds.points().collect::<HashSet<_>>(), points);
+}
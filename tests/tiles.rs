@@ -0,0 +1,171 @@
+use euclid::{Box2D, Box3D, Rect};
+use euclid_ext::{Points, Tiles};
+
+enum Space {}
+
+type Point2D<T> = euclid::Point2D<T, Space>;
+
+type Size2D<T> = euclid::Size2D<T, Space>;
+
+type Point3D<T> = euclid::Point3D<T, Space>;
+
+type Size3D<T> = euclid::Size3D<T, Space>;
+
+#[test]
+fn tiles_of_box2d() {
+    let tiles = Box2D::new(Point2D::new(10, 20), Point2D::new(17, 26)).tiles(Size2D::new(3, 4));
+    assert_eq!(6, tiles.len());
+    assert_eq!(
+        vec![
+            Box2D::new(Point2D::new(10, 20), Point2D::new(13, 24)),
+            Box2D::new(Point2D::new(13, 20), Point2D::new(16, 24)),
+            Box2D::new(Point2D::new(16, 20), Point2D::new(17, 24)),
+            Box2D::new(Point2D::new(10, 24), Point2D::new(13, 26)),
+            Box2D::new(Point2D::new(13, 24), Point2D::new(16, 26)),
+            Box2D::new(Point2D::new(16, 24), Point2D::new(17, 26))
+        ],
+        tiles.collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn tiles_of_rect() {
+    assert_eq!(
+        vec![
+            Box2D::new(Point2D::new(0, 0), Point2D::new(2, 2)),
+            Box2D::new(Point2D::new(2, 0), Point2D::new(4, 2))
+        ],
+        Rect::new(Point2D::new(0, 0), Size2D::new(4, 2))
+            .tiles(Size2D::new(2, 2))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn tiles_of_box3d() {
+    let range = Box3D::new(Point3D::new(0, 0, 0), Point3D::new(5, 4, 3));
+    let tiles = range.tiles(Size3D::new(2, 4, 2)).collect::<Vec<_>>();
+    assert_eq!(6, tiles.len());
+    assert_eq!(
+        Box3D::new(Point3D::new(4, 0, 2), Point3D::new(5, 4, 3)),
+        tiles[5]
+    );
+    let mut points = tiles
+        .into_iter()
+        .flat_map(|tile| tile.points())
+        .collect::<Vec<_>>();
+    points.sort_by_key(|p| (p.z, p.y, p.x));
+    assert_eq!(range.points().collect::<Vec<_>>(), points);
+}
+
+#[test]
+fn tiles_with_halo() {
+    let range = Box2D::new(Point2D::new(0u32, 0), Point2D::new(6, 3));
+    assert_eq!(
+        vec![
+            Box2D::new(Point2D::new(0, 0), Point2D::new(4, 3)),
+            Box2D::new(Point2D::new(2, 0), Point2D::new(6, 3))
+        ],
+        range
+            .tiles_with_halo(Size2D::new(3, 3), Size2D::new(1, 1))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn tiles_with_halo_at_type_bounds() {
+    let range = Box2D::new(Point2D::new(250u8, 0), Point2D::new(u8::MAX, 1));
+    assert_eq!(
+        vec![
+            Box2D::new(Point2D::new(250, 0), Point2D::new(254, 1)),
+            Box2D::new(Point2D::new(252, 0), Point2D::new(255, 1))
+        ],
+        range
+            .tiles_with_halo(Size2D::new(3, 1), Size2D::new(1, 0))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn tiles_with_halo_larger_than_type() {
+    let range = Box2D::new(Point2D::new(0u8, 0), Point2D::new(u8::MAX, 1));
+    assert_eq!(
+        vec![
+            Box2D::new(Point2D::new(0, 0), Point2D::new(u8::MAX, 1)),
+            Box2D::new(Point2D::new(100, 0), Point2D::new(u8::MAX, 1))
+        ],
+        range
+            .tiles_with_halo(Size2D::new(200, 1), Size2D::new(100, 0))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn tiles_in_reverse() {
+    let range = Box2D::new(Point2D::new(0, 0), Point2D::new(5, 5));
+    let mut tiles = range.tiles(Size2D::new(2, 2)).collect::<Vec<_>>();
+    tiles.reverse();
+    assert_eq!(
+        tiles,
+        range.tiles(Size2D::new(2, 2)).rev().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn points_by_tile() {
+    let range = Box3D::new(Point3D::new(1, 2, 3), Point3D::new(8, 7, 6));
+    let points = range
+        .points_by_tile(Size3D::new(4, 3, 2))
+        .collect::<Vec<_>>();
+    assert_eq!(range.points().len(), points.len());
+    assert_eq!(
+        range
+            .tiles(Size3D::new(4, 3, 2))
+            .next()
+            .unwrap()
+            .points()
+            .collect::<Vec<_>>(),
+        points[..24].to_vec()
+    );
+    let mut sorted = points;
+    sorted.sort_by_key(|p| (p.z, p.y, p.x));
+    assert_eq!(range.points().collect::<Vec<_>>(), sorted);
+}
+
+#[test]
+fn tiles_of_empty_range() {
+    assert_eq!(
+        0,
+        Box2D::new(Point2D::new(3, 3), Point2D::new(3, 9))
+            .tiles(Size2D::new(2, 2))
+            .count()
+    );
+}
+
+#[test]
+fn tiles_of_inclusive_range() {
+    let range = Point2D::new(1u8, 0)..=Point2D::new(5, 2);
+    assert_eq!(
+        vec![
+            Box2D::new(Point2D::new(1, 0), Point2D::new(4, 2)),
+            Box2D::new(Point2D::new(4, 0), Point2D::new(6, 2)),
+            Box2D::new(Point2D::new(1, 2), Point2D::new(4, 3)),
+            Box2D::new(Point2D::new(4, 2), Point2D::new(6, 3))
+        ],
+        range.clone().tiles(Size2D::new(3, 2)).collect::<Vec<_>>()
+    );
+    let mut points = range
+        .clone()
+        .points_by_tile(Size2D::new(3, 2))
+        .collect::<Vec<_>>();
+    points.sort_by_key(|p| (p.y, p.x));
+    assert_eq!(range.points().collect::<Vec<_>>(), points);
+}
+
+#[test]
+#[should_panic(expected = "tile ends past the maximum of the coordinate type")]
+fn tiles_of_inclusive_range_at_maximum() {
+    (Point2D::new(0u8, 0)..=Point2D::new(u8::MAX, 0))
+        .tiles(Size2D::new(16, 1))
+        .next_back();
+}